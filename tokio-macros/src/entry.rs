@@ -21,10 +21,15 @@ impl RuntimeFlavor {
     }
 }
 
-struct FinalConfig {
-    flavor: RuntimeFlavor,
-    worker_threads: Option<usize>,
-    start_paused: Option<bool>,
+enum FinalConfig {
+    Default {
+        flavor: RuntimeFlavor,
+        worker_threads: Option<usize>,
+        start_paused: Option<bool>,
+    },
+    CustomBuilder {
+        path: syn::Path,
+    },
 }
 
 struct Configuration {
@@ -33,6 +38,7 @@ struct Configuration {
     flavor: Option<RuntimeFlavor>,
     worker_threads: Option<(usize, Span)>,
     start_paused: Option<(bool, Span)>,
+    builder: Option<(syn::Path, Span)>,
     is_test: bool,
 }
 
@@ -47,6 +53,7 @@ impl Configuration {
             flavor: None,
             worker_threads: None,
             start_paused: None,
+            builder: None,
             is_test,
         }
     }
@@ -93,6 +100,19 @@ impl Configuration {
         Ok(())
     }
 
+    fn set_builder(&mut self, builder: syn::Lit, span: Span) -> Result<(), syn::Error> {
+        if self.builder.is_some() {
+            return Err(syn::Error::new(span, "`builder` set multiple times."));
+        }
+
+        let builder = parse_string(builder, span, "builder")?;
+        let path = syn::parse_str(&builder).map_err(|_| {
+            syn::Error::new(span, format!("Failed to parse path of `builder`: {}", builder))
+        })?;
+        self.builder = Some((path, span));
+        Ok(())
+    }
+
     fn macro_name(&self) -> &'static str {
         if self.is_test {
             "tokio::test"
@@ -102,6 +122,25 @@ impl Configuration {
     }
 
     fn build(&self) -> Result<FinalConfig, syn::Error> {
+        if let Some((path, builder_span)) = &self.builder {
+            let msg = |conflicting: &str| {
+                format!(
+                    "`builder` delegates runtime construction to a user function and cannot be combined with `{}`. Configure the runtime inside that function instead.",
+                    conflicting,
+                )
+            };
+            if self.flavor.is_some() {
+                return Err(syn::Error::new(*builder_span, msg("flavor")));
+            }
+            if let Some((_, span)) = self.worker_threads {
+                return Err(syn::Error::new(span, msg("worker_threads")));
+            }
+            if let Some((_, span)) = self.start_paused {
+                return Err(syn::Error::new(span, msg("start_paused")));
+            }
+            return Ok(FinalConfig::CustomBuilder { path: path.clone() });
+        }
+
         let flavor = self.flavor.unwrap_or(self.default_flavor);
         use RuntimeFlavor::*;
 
@@ -139,7 +178,7 @@ impl Configuration {
             (_, None) => None,
         };
 
-        Ok(FinalConfig {
+        Ok(FinalConfig::Default {
             flavor,
             worker_threads,
             start_paused,
@@ -228,13 +267,19 @@ fn parse_knobs(
                             syn::spanned::Spanned::span(&namevalue.lit),
                         )?;
                     }
+                    "builder" => {
+                        config.set_builder(
+                            namevalue.lit.clone(),
+                            syn::spanned::Spanned::span(&namevalue.lit),
+                        )?;
+                    }
                     "core_threads" => {
                         let msg = "Attribute `core_threads` is renamed to `worker_threads`";
                         return Err(syn::Error::new_spanned(namevalue, msg));
                     }
                     name => {
                         let msg = format!(
-                            "Unknown attribute {} is specified; expected one of: `flavor`, `worker_threads`, `start_paused`",
+                            "Unknown attribute {} is specified; expected one of: `flavor`, `worker_threads`, `start_paused`, `builder`",
                             name,
                         );
                         return Err(syn::Error::new_spanned(namevalue, msg));
@@ -260,11 +305,11 @@ fn parse_knobs(
                             macro_name
                         )
                     }
-                    "flavor" | "worker_threads" | "start_paused" => {
+                    "flavor" | "worker_threads" | "start_paused" | "builder" => {
                         format!("The `{}` attribute requires an argument.", name)
                     }
                     name => {
-                        format!("Unknown attribute {} is specified; expected one of: `flavor`, `worker_threads`, `start_paused`", name)
+                        format!("Unknown attribute {} is specified; expected one of: `flavor`, `worker_threads`, `start_paused`, `builder`", name)
                     }
                 };
                 return Err(syn::Error::new_spanned(path, msg));
@@ -298,20 +343,32 @@ fn parse_knobs(
         (start, end)
     };
 
-    let mut rt = match config.flavor {
-        RuntimeFlavor::CurrentThread => quote_spanned! {last_stmt_start_span=>
-            tokio::runtime::Builder::new_current_thread()
-        },
-        RuntimeFlavor::Threaded => quote_spanned! {last_stmt_start_span=>
-            tokio::runtime::Builder::new_multi_thread()
+    let build_rt = match config {
+        FinalConfig::CustomBuilder { path } => quote_spanned! {last_stmt_start_span=>
+            (#path)()
         },
+        FinalConfig::Default {
+            flavor,
+            worker_threads,
+            start_paused,
+        } => {
+            let mut rt = match flavor {
+                RuntimeFlavor::CurrentThread => quote_spanned! {last_stmt_start_span=>
+                    tokio::runtime::Builder::new_current_thread()
+                },
+                RuntimeFlavor::Threaded => quote_spanned! {last_stmt_start_span=>
+                    tokio::runtime::Builder::new_multi_thread()
+                },
+            };
+            if let Some(v) = worker_threads {
+                rt = quote! { #rt.worker_threads(#v) };
+            }
+            if let Some(v) = start_paused {
+                rt = quote! { #rt.start_paused(#v) };
+            }
+            quote! { #rt.enable_all() }
+        }
     };
-    if let Some(v) = config.worker_threads {
-        rt = quote! { #rt.worker_threads(#v) };
-    }
-    if let Some(v) = config.start_paused {
-        rt = quote! { #rt.start_paused(#v) };
-    }
 
     let header = if is_test {
         quote! {
@@ -325,8 +382,7 @@ fn parse_knobs(
     let brace_token = input.block.brace_token;
     input.block = syn::parse2(quote_spanned! {last_stmt_end_span=>
         {
-            #rt
-                .enable_all()
+            #build_rt
                 .build()
                 .expect("Failed building the Runtime")
                 .block_on(async #body)
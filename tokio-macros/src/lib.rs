@@ -169,6 +169,63 @@ use proc_macro::TokenStream;
 ///
 /// Note that `start_paused` requires the `test-util` feature to be enabled.
 ///
+/// ### Combining options
+///
+/// The `flavor`, `worker_threads`, and `start_paused` options can be mixed
+/// and matched as needed, as long as the combination makes sense for the
+/// chosen flavor (`worker_threads` requires `multi_thread`, and
+/// `start_paused` requires `current_thread`):
+///
+/// ```rust
+/// #[tokio::main(flavor = "multi_thread", worker_threads = 4)]
+/// async fn main() {
+///     println!("Hello world");
+/// }
+/// ```
+///
+/// Equivalent code not using `#[tokio::main]`
+///
+/// ```rust
+/// fn main() {
+///     tokio::runtime::Builder::new_multi_thread()
+///         .worker_threads(4)
+///         .enable_all()
+///         .build()
+///         .unwrap()
+///         .block_on(async {
+///             println!("Hello world");
+///         })
+/// }
+/// ```
+///
+/// ### Custom runtime construction
+///
+/// For setups the other options don't cover — custom thread names, a
+/// `on_thread_start`/`on_thread_stop` hook, a `max_tasks` limit, and so on — the
+/// `builder` option delegates the entire construction of the `Builder` to a
+/// function you provide. The function takes no arguments and returns a
+/// `tokio::runtime::Builder`; the macro then calls `.build()` on it:
+///
+/// ```rust
+/// fn my_builder() -> tokio::runtime::Builder {
+///     let mut builder = tokio::runtime::Builder::new_multi_thread();
+///     builder
+///         .worker_threads(4)
+///         .thread_name("my-app-worker")
+///         .enable_all();
+///     builder
+/// }
+///
+/// #[tokio::main(builder = "my_builder")]
+/// async fn main() {
+///     println!("Hello world");
+/// }
+/// ```
+///
+/// Because the function is responsible for the whole `Builder`, including
+/// which drivers are enabled, `builder` cannot be combined with `flavor`,
+/// `worker_threads`, or `start_paused` — set those on the builder itself.
+///
 /// ### NOTE:
 ///
 /// If you rename the Tokio crate in your dependencies this macro will not work.
@@ -261,6 +318,42 @@ pub fn main_rt(args: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// Note that `start_paused` requires the `test-util` feature to be enabled.
 ///
+/// ### Combining options
+///
+/// The `flavor`, `worker_threads`, and `start_paused` options can be mixed
+/// and matched as needed, as long as the combination makes sense for the
+/// chosen flavor (`worker_threads` requires `multi_thread`, and
+/// `start_paused` requires `current_thread`). This is useful for
+/// scheduler-sensitive bugs that only reproduce on the multi-threaded
+/// runtime:
+///
+/// ```no_run
+/// #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+/// async fn my_test() {
+///     assert!(true);
+/// }
+/// ```
+///
+/// ### Custom runtime construction
+///
+/// Like `#[tokio::main]`, `#[tokio::test]` accepts a `builder` option that
+/// delegates runtime construction to a function returning a
+/// `tokio::runtime::Builder`, for test suites that standardize on a shared
+/// runtime configuration:
+///
+/// ```no_run
+/// fn my_builder() -> tokio::runtime::Builder {
+///     let mut builder = tokio::runtime::Builder::new_current_thread();
+///     builder.enable_all();
+///     builder
+/// }
+///
+/// #[tokio::test(builder = "my_builder")]
+/// async fn my_test() {
+///     assert!(true);
+/// }
+/// ```
+///
 /// ### NOTE:
 ///
 /// If you rename the Tokio crate in your dependencies this macro will not work.
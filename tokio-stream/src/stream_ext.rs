@@ -54,6 +54,10 @@ cfg_time! {
     use tokio::time::Duration;
     mod throttle;
     use throttle::{throttle, Throttle};
+    mod chunks_timeout;
+    use chunks_timeout::ChunksTimeout;
+    mod debounce;
+    use debounce::Debounce;
 }
 
 /// An extension trait for the [`Stream`] trait that provides a variety of
@@ -824,7 +828,9 @@ pub trait StreamExt: Stream {
     /// wrapped version of it.
     ///
     /// Polling the returned stream will continue to poll the inner stream even
-    /// if one or more items time out.
+    /// if one or more items time out. This makes it useful for detecting a
+    /// stalled upstream feed (each item's lateness is reported as it happens)
+    /// without having to tear down and recreate the stream.
     ///
     /// # Examples
     ///
@@ -899,6 +905,86 @@ pub trait StreamExt: Stream {
     {
         throttle(duration, self)
     }
+
+    /// Batches the items of the stream into `Vec`s, emitting one as soon as
+    /// either `max_size` items have accumulated or `duration` has elapsed
+    /// since the first item of the batch arrived.
+    ///
+    /// An empty batch is never emitted purely because of the timeout: the
+    /// deadline only starts (and can only fire) once at least one item has
+    /// been buffered. The final batch, if non-empty, is flushed when the
+    /// underlying stream ends, even if it's smaller than `max_size`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `max_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// # async fn dox() {
+    /// let item_stream = futures::stream::repeat("one");
+    /// tokio::pin!(item_stream);
+    ///
+    /// let batches = item_stream.chunks_timeout(100, Duration::from_millis(100));
+    /// tokio::pin!(batches);
+    ///
+    /// // Each batch has at most 100 items, and is emitted at least every
+    /// // 100ms even if it doesn't fill up.
+    /// while let Some(batch) = batches.next().await {
+    ///     println!("writing batch of {} items", batch.len());
+    /// }
+    /// # }
+    /// ```
+    #[cfg(all(feature = "time"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    fn chunks_timeout(self, max_size: usize, duration: Duration) -> ChunksTimeout<Self>
+    where
+        Self: Sized,
+    {
+        assert!(max_size > 0, "`max_size` must be greater than zero");
+        ChunksTimeout::new(self, max_size, duration)
+    }
+
+    /// Suppresses rapid-fire items, only yielding the most recent one once
+    /// the stream has gone `duration` without producing another.
+    ///
+    /// Every new item restarts the `duration` timer and replaces whichever
+    /// item was pending, so a burst of items collapses down to just the
+    /// last one in the burst. A pending item is always flushed once the
+    /// underlying stream ends, even if `duration` hasn't elapsed yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// # async fn dox() {
+    /// let fs_events = futures::stream::repeat("changed");
+    /// tokio::pin!(fs_events);
+    ///
+    /// let settled = fs_events.debounce(Duration::from_millis(200));
+    /// tokio::pin!(settled);
+    ///
+    /// // Only the last event in a burst of rapid filesystem notifications
+    /// // is yielded, 200ms after the burst quiets down.
+    /// while let Some(event) = settled.next().await {
+    ///     println!("{}", event);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(all(feature = "time"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    fn debounce(self, duration: Duration) -> Debounce<Self>
+    where
+        Self: Sized,
+    {
+        Debounce::new(self, duration)
+    }
 }
 
 impl<St: ?Sized> StreamExt for St where St: Stream {}
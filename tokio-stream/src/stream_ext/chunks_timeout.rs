@@ -0,0 +1,78 @@
+use crate::Stream;
+use tokio::time::{Instant, Sleep};
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+use std::time::Duration;
+
+pin_project! {
+    /// Stream returned by the [`chunks_timeout`](super::StreamExt::chunks_timeout) method.
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct ChunksTimeout<S: Stream> {
+        #[pin]
+        stream: S,
+        #[pin]
+        deadline: Sleep,
+        duration: Duration,
+        max_size: usize,
+        items: Vec<S::Item>,
+    }
+}
+
+impl<S: Stream> ChunksTimeout<S> {
+    pub(super) fn new(stream: S, max_size: usize, duration: Duration) -> Self {
+        ChunksTimeout {
+            stream,
+            deadline: tokio::time::sleep(duration),
+            duration,
+            max_size,
+            items: Vec::with_capacity(max_size),
+        }
+    }
+}
+
+impl<S: Stream> Stream for ChunksTimeout<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if me.items.is_empty() {
+                        me.deadline
+                            .as_mut()
+                            .reset(Instant::now() + *me.duration);
+                    }
+
+                    me.items.push(item);
+
+                    if me.items.len() >= *me.max_size {
+                        return Poll::Ready(Some(std::mem::take(me.items)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    return if me.items.is_empty() {
+                        Poll::Ready(None)
+                    } else {
+                        Poll::Ready(Some(std::mem::take(me.items)))
+                    };
+                }
+                Poll::Pending => break,
+            }
+        }
+
+        if me.items.is_empty() {
+            return Poll::Pending;
+        }
+
+        match me.deadline.as_mut().poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Some(std::mem::take(me.items))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
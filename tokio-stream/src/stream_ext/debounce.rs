@@ -0,0 +1,78 @@
+use crate::Stream;
+use tokio::time::{Instant, Sleep};
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use pin_project_lite::pin_project;
+use std::time::Duration;
+
+pin_project! {
+    /// Stream returned by the [`debounce`](super::StreamExt::debounce) method.
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct Debounce<S: Stream> {
+        #[pin]
+        stream: S,
+        #[pin]
+        deadline: Sleep,
+        duration: Duration,
+        pending: Option<S::Item>,
+        stream_done: bool,
+    }
+}
+
+impl<S: Stream> Debounce<S> {
+    pub(super) fn new(stream: S, duration: Duration) -> Self {
+        Debounce {
+            stream,
+            deadline: tokio::time::sleep(duration),
+            duration,
+            pending: None,
+            stream_done: false,
+        }
+    }
+}
+
+impl<S: Stream> Stream for Debounce<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if !*me.stream_done {
+            loop {
+                match me.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        *me.pending = Some(item);
+                        me.deadline
+                            .as_mut()
+                            .reset(Instant::now() + *me.duration);
+                    }
+                    Poll::Ready(None) => {
+                        *me.stream_done = true;
+                        break;
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        if me.pending.is_none() {
+            return if *me.stream_done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+
+        if *me.stream_done {
+            return Poll::Ready(me.pending.take());
+        }
+
+        match me.deadline.as_mut().poll(cx) {
+            Poll::Ready(()) => Poll::Ready(me.pending.take()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
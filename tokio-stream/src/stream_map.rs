@@ -2,6 +2,7 @@ use crate::Stream;
 
 use std::borrow::Borrow;
 use std::hash::Hash;
+use std::iter::FromIterator;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
@@ -533,6 +534,28 @@ impl<K, V> Default for StreamMap<K, V> {
     }
 }
 
+impl<K, V> Extend<(K, V)> for StreamMap<K, V>
+where
+    K: Hash + Eq,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (k, stream) in iter {
+            self.insert(k, stream);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for StreamMap<K, V>
+where
+    K: Hash + Eq,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = StreamMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
 impl<K, V> Stream for StreamMap<K, V>
 where
     K: Clone + Unpin,
@@ -10,6 +10,25 @@ use std::task::{Context, Poll};
 
 /// A wrapper around [`tokio::sync::broadcast::Receiver`] that implements [`Stream`].
 ///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use tokio::sync::broadcast;
+/// use tokio_stream::{StreamExt, wrappers::BroadcastStream};
+///
+/// let (tx, rx) = broadcast::channel(16);
+/// let mut stream = BroadcastStream::new(rx);
+///
+/// tx.send("hello").unwrap();
+/// drop(tx);
+///
+/// assert_eq!(stream.next().await, Some(Ok("hello")));
+/// assert_eq!(stream.next().await, None);
+/// # }
+/// ```
+///
 /// [`tokio::sync::broadcast::Receiver`]: struct@tokio::sync::broadcast::Receiver
 /// [`Stream`]: trait@crate::Stream
 #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
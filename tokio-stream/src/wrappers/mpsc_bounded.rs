@@ -5,6 +5,25 @@ use tokio::sync::mpsc::Receiver;
 
 /// A wrapper around [`tokio::sync::mpsc::Receiver`] that implements [`Stream`].
 ///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use tokio::sync::mpsc;
+/// use tokio_stream::{StreamExt, wrappers::ReceiverStream};
+///
+/// let (tx, rx) = mpsc::channel(16);
+/// let mut stream = ReceiverStream::new(rx);
+///
+/// tx.send("hello").await.unwrap();
+/// drop(tx);
+///
+/// assert_eq!(stream.next().await, Some("hello"));
+/// assert_eq!(stream.next().await, None);
+/// # }
+/// ```
+///
 /// [`tokio::sync::mpsc::Receiver`]: struct@tokio::sync::mpsc::Receiver
 /// [`Stream`]: trait@crate::Stream
 #[derive(Debug)]
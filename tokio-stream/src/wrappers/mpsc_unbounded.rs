@@ -5,6 +5,25 @@ use tokio::sync::mpsc::UnboundedReceiver;
 
 /// A wrapper around [`tokio::sync::mpsc::UnboundedReceiver`] that implements [`Stream`].
 ///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use tokio::sync::mpsc;
+/// use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
+///
+/// let (tx, rx) = mpsc::unbounded_channel();
+/// let mut stream = UnboundedReceiverStream::new(rx);
+///
+/// tx.send("hello").unwrap();
+/// drop(tx);
+///
+/// assert_eq!(stream.next().await, Some("hello"));
+/// assert_eq!(stream.next().await, None);
+/// # }
+/// ```
+///
 /// [`tokio::sync::mpsc::UnboundedReceiver`]: struct@tokio::sync::mpsc::UnboundedReceiver
 /// [`Stream`]: trait@crate::Stream
 #[derive(Debug)]
@@ -0,0 +1,49 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use tokio::sync::mpsc;
+use tokio::time;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+use tokio_test::*;
+
+use std::time::Duration;
+
+#[tokio::test]
+async fn batches_by_size() {
+    time::pause();
+
+    let (tx, rx) = mpsc::channel(10);
+    let mut batches = task::spawn(ReceiverStream::new(rx).chunks_timeout(2, Duration::from_secs(10)));
+
+    tx.send(1).await.unwrap();
+    assert_pending!(batches.poll_next());
+
+    tx.send(2).await.unwrap();
+    assert_ready_eq!(batches.poll_next(), Some(vec![1, 2]));
+}
+
+#[tokio::test]
+async fn batches_by_timeout() {
+    time::pause();
+
+    let (tx, rx) = mpsc::channel(10);
+    let mut batches = task::spawn(ReceiverStream::new(rx).chunks_timeout(10, Duration::from_millis(100)));
+
+    tx.send(1).await.unwrap();
+    assert_pending!(batches.poll_next());
+
+    time::advance(Duration::from_millis(101)).await;
+
+    assert_ready_eq!(batches.poll_next(), Some(vec![1]));
+}
+
+#[tokio::test]
+async fn flushes_final_partial_batch_on_end() {
+    let batches = tokio_stream::iter(vec![1, 2, 3]).chunks_timeout(2, Duration::from_secs(10));
+    tokio::pin!(batches);
+
+    assert_eq!(batches.next().await, Some(vec![1, 2]));
+    assert_eq!(batches.next().await, Some(vec![3]));
+    assert_eq!(batches.next().await, None);
+}
@@ -0,0 +1,37 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use tokio::sync::mpsc;
+use tokio::time;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+use tokio_test::*;
+
+use std::time::Duration;
+
+#[tokio::test]
+async fn collapses_a_burst_to_the_last_item() {
+    time::pause();
+
+    let (tx, rx) = mpsc::channel(10);
+    let mut debounced = task::spawn(ReceiverStream::new(rx).debounce(Duration::from_millis(100)));
+
+    tx.send(1).await.unwrap();
+    assert_pending!(debounced.poll_next());
+
+    time::advance(Duration::from_millis(50)).await;
+    tx.send(2).await.unwrap();
+    assert_pending!(debounced.poll_next());
+
+    time::advance(Duration::from_millis(101)).await;
+    assert_ready_eq!(debounced.poll_next(), Some(2));
+}
+
+#[tokio::test]
+async fn flushes_pending_item_on_end() {
+    let stream = tokio_stream::iter(vec![1, 2, 3]).debounce(Duration::from_secs(10));
+    tokio::pin!(stream);
+
+    assert_eq!(stream.next().await, Some(3));
+    assert_eq!(stream.next().await, None);
+}
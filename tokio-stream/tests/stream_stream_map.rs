@@ -301,6 +301,20 @@ fn clear() {
     assert!(map.is_empty());
 }
 
+#[test]
+fn from_iter_and_extend() {
+    let mut map: StreamMap<_, _> = vec![("a", stream::iter(vec![1])), ("b", stream::iter(vec![2]))]
+        .into_iter()
+        .collect();
+
+    assert_eq!(map.len(), 2);
+
+    map.extend(vec![("c", stream::iter(vec![3]))]);
+
+    assert_eq!(map.len(), 3);
+    assert!(map.contains_key("c"));
+}
+
 #[test]
 fn contains_key_borrow() {
     let mut map = StreamMap::new();
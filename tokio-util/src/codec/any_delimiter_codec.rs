@@ -129,6 +129,19 @@ impl AnyDelimiterCodec {
     pub fn max_length(&self) -> usize {
         self.max_length
     }
+
+    /// Sets the maximum length for a given chunk.
+    ///
+    /// ```
+    /// use tokio_util::codec::AnyDelimiterCodec;
+    ///
+    /// let mut codec = AnyDelimiterCodec::new(b",;\n".to_vec(), b";".to_vec());
+    /// codec.set_max_length(256);
+    /// assert_eq!(codec.max_length(), 256);
+    /// ```
+    pub fn set_max_length(&mut self, max_length: usize) {
+        self.max_length = max_length;
+    }
 }
 
 impl Decoder for AnyDelimiterCodec {
@@ -88,6 +88,19 @@ impl LinesCodec {
     pub fn max_length(&self) -> usize {
         self.max_length
     }
+
+    /// Sets the maximum length for a given line.
+    ///
+    /// ```
+    /// use tokio_util::codec::LinesCodec;
+    ///
+    /// let mut codec = LinesCodec::new();
+    /// codec.set_max_length(256);
+    /// assert_eq!(codec.max_length(), 256);
+    /// ```
+    pub fn set_max_length(&mut self, max_length: usize) {
+        self.max_length = max_length;
+    }
 }
 
 fn utf8(buf: &[u8]) -> Result<&str, io::Error> {
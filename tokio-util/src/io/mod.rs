@@ -10,7 +10,14 @@ mod read_buf;
 mod reader_stream;
 mod stream_reader;
 
+#[cfg(feature = "rt")]
+mod sync_bridge;
+
 pub use self::read_buf::read_buf;
 pub use self::reader_stream::ReaderStream;
 pub use self::stream_reader::StreamReader;
 pub use crate::util::{poll_read_buf, poll_write_buf};
+
+#[cfg(feature = "rt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
+pub use self::sync_bridge::SyncIoBridge;
@@ -0,0 +1,102 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+
+/// Wraps an async stream and implements the std blocking I/O traits
+/// ([`Read`], [`Write`], [`Seek`]) for it, by entering the owning [`Handle`]
+/// and blocking on the equivalent async operation.
+///
+/// This lets sync code (for example a third-party library that only knows
+/// `std::io::Read`, such as a zip or image decoder) drive an async stream.
+/// Because it blocks the calling thread for the duration of each operation,
+/// `SyncIoBridge` must only be used from a context where blocking is
+/// acceptable, such as inside [`spawn_blocking`].
+///
+/// # Example
+///
+/// ```
+/// use tokio::io::AsyncRead;
+/// use tokio_util::io::SyncIoBridge;
+///
+/// # async fn docs(input: impl AsyncRead + Unpin + Send + 'static) -> std::io::Result<()> {
+/// let mut sync_reader = SyncIoBridge::new(input);
+///
+/// // Run in a blocking task, since `SyncIoBridge` blocks the thread it runs on.
+/// let output = tokio::task::spawn_blocking(move || -> std::io::Result<Vec<u8>> {
+///     let mut buf = Vec::new();
+///     std::io::copy(&mut sync_reader, &mut buf)?;
+///     Ok(buf)
+/// })
+/// .await??;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Handle`]: tokio::runtime::Handle
+/// [`spawn_blocking`]: tokio::task::spawn_blocking
+#[derive(Debug)]
+pub struct SyncIoBridge<T> {
+    src: T,
+    handle: tokio::runtime::Handle,
+}
+
+impl<T: AsyncRead + Unpin> Read for SyncIoBridge<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let src = &mut self.src;
+        self.handle.block_on(src.read(buf))
+    }
+}
+
+impl<T: AsyncWrite + Unpin> Write for SyncIoBridge<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let src = &mut self.src;
+        self.handle.block_on(src.write(buf))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        let src = &mut self.src;
+        self.handle.block_on(src.flush())
+    }
+}
+
+impl<T: AsyncSeek + Unpin> Seek for SyncIoBridge<T> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let src = &mut self.src;
+        self.handle.block_on(src.seek(pos))
+    }
+}
+
+impl<T> SyncIoBridge<T> {
+    /// Wraps `src` for use from a blocking context, using the [`Handle`] of
+    /// the runtime that is currently entered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a Tokio runtime.
+    ///
+    /// [`Handle`]: tokio::runtime::Handle
+    pub fn new(src: T) -> Self {
+        Self::new_with_handle(src, tokio::runtime::Handle::current())
+    }
+
+    /// Wraps `src` for use from a blocking context, using the given
+    /// [`Handle`] rather than the handle of the currently entered runtime.
+    ///
+    /// [`Handle`]: tokio::runtime::Handle
+    pub fn new_with_handle(src: T, handle: tokio::runtime::Handle) -> Self {
+        Self { src, handle }
+    }
+
+    /// Unwraps this `SyncIoBridge`, returning the underlying async stream.
+    pub fn into_inner(self) -> T {
+        self.src
+    }
+}
+
+impl<T: AsyncWrite + Unpin> SyncIoBridge<T> {
+    /// Shuts down the underlying async stream, via [`AsyncWriteExt::shutdown`].
+    pub fn shutdown(&mut self) -> std::io::Result<()> {
+        let src = &mut self.src;
+        self.handle.block_on(src.shutdown())
+    }
+}
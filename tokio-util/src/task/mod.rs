@@ -0,0 +1,5 @@
+//! Extra utilities for spawning tasks.
+
+mod task_tracker;
+
+pub use task_tracker::{TaskTracker, TaskTrackerWaitFuture};
@@ -0,0 +1,253 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use pin_project_lite::pin_project;
+use tokio::sync::futures::Notified;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+#[derive(Debug)]
+struct Inner {
+    count: AtomicUsize,
+    closed: AtomicBool,
+    notify: Notify,
+}
+
+impl Inner {
+    fn track(&self) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn untrack(&self) {
+        if self.count.fetch_sub(1, Ordering::AcqRel) == 1 && self.closed.load(Ordering::Acquire) {
+            self.notify.notify_waiters();
+        }
+    }
+}
+
+/// Tracks a collection of spawned tasks so that callers can wait for all of
+/// them to finish.
+///
+/// Every task spawned through [`TaskTracker::spawn`] (or wrapped with
+/// [`TaskTracker::track_future`]) is counted. Once the tracker is
+/// [closed](TaskTracker::close), [`wait`](TaskTracker::wait) resolves as
+/// soon as the last tracked task completes — including tasks spawned after
+/// `wait` started waiting, as long as they finish before the count reaches
+/// zero.
+///
+/// `TaskTracker` composes naturally with [`CancellationToken`]: cancel the
+/// token to ask tasks to stop, close the tracker so no new tasks are
+/// expected, then `wait()` for the ones already running to actually finish.
+///
+/// [`CancellationToken`]: crate::sync::CancellationToken
+///
+/// # Example
+///
+/// ```
+/// use tokio_util::task::TaskTracker;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let tracker = TaskTracker::new();
+///
+///     for i in 0..3 {
+///         tracker.spawn(async move {
+///             println!("task {} running", i);
+///         });
+///     }
+///
+///     // No more tasks will be spawned.
+///     tracker.close();
+///
+///     // Wait for all tasks to finish.
+///     tracker.wait().await;
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskTracker {
+    inner: Arc<Inner>,
+}
+
+impl TaskTracker {
+    /// Creates a new, open `TaskTracker`.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                count: AtomicUsize::new(0),
+                closed: AtomicBool::new(false),
+                notify: Notify::new(),
+            }),
+        }
+    }
+
+    /// Spawns a task tracked by this `TaskTracker`, returning its
+    /// [`JoinHandle`].
+    ///
+    /// This is equivalent to `tokio::spawn(tracker.track_future(task))`.
+    pub fn spawn<F>(&self, task: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        tokio::spawn(self.track_future(task))
+    }
+
+    /// Spawns a blocking task tracked by this `TaskTracker`, returning its
+    /// [`JoinHandle`].
+    pub fn spawn_blocking<F, T>(&self, f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        self.inner.track();
+        let inner = self.inner.clone();
+        tokio::task::spawn_blocking(move || {
+            let res = f();
+            inner.untrack();
+            res
+        })
+    }
+
+    /// Wraps `future` so that it is tracked by this `TaskTracker` until the
+    /// returned [`TrackedFuture`] is dropped.
+    ///
+    /// Polling it to completion does not untrack it by itself: a completed
+    /// `TrackedFuture` that is kept alive still counts as tracked.
+    ///
+    /// Unlike [`spawn`](TaskTracker::spawn), this does not spawn the future
+    /// onto a runtime — the caller decides how it gets polled.
+    pub fn track_future<F: Future>(&self, future: F) -> TrackedFuture<F> {
+        self.inner.track();
+        TrackedFuture {
+            inner: self.inner.clone(),
+            future,
+        }
+    }
+
+    /// Closes the tracker, preventing any further calls to [`wait`] from
+    /// blocking forever once the currently tracked tasks finish.
+    ///
+    /// Returns `true` if this call closed the tracker, or `false` if it was
+    /// already closed. Closing does not cancel or otherwise affect tasks
+    /// that are already tracked — it only affects when [`wait`] resolves.
+    ///
+    /// [`wait`]: TaskTracker::wait
+    pub fn close(&self) -> bool {
+        let was_closed = self.inner.closed.swap(true, Ordering::AcqRel);
+        if !was_closed && self.inner.count.load(Ordering::Acquire) == 0 {
+            self.inner.notify.notify_waiters();
+        }
+        !was_closed
+    }
+
+    /// Reopens the tracker, so that [`wait`](TaskTracker::wait) will once
+    /// again wait until [`close`](TaskTracker::close) is called.
+    ///
+    /// Returns `true` if this call reopened the tracker, or `false` if it
+    /// was already open.
+    pub fn reopen(&self) -> bool {
+        self.inner.closed.swap(false, Ordering::AcqRel)
+    }
+
+    /// Returns `true` if the tracker is closed.
+    pub fn is_closed(&self) -> bool {
+        self.inner.closed.load(Ordering::Acquire)
+    }
+
+    /// Returns the number of tasks currently tracked.
+    pub fn len(&self) -> usize {
+        self.inner.count.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if there are no tasks currently tracked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Waits until the tracker is closed and all tracked tasks have
+    /// finished.
+    pub fn wait(&self) -> TaskTrackerWaitFuture<'_> {
+        TaskTrackerWaitFuture {
+            inner: &self.inner,
+            notified: self.inner.notify.notified(),
+        }
+    }
+}
+
+impl Default for TaskTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pin_project! {
+    /// A future that delegates to an inner future while it is tracked by a
+    /// [`TaskTracker`].
+    ///
+    /// Created by [`TaskTracker::track_future`].
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless polled"]
+    pub struct TrackedFuture<F> {
+        inner: Arc<Inner>,
+        #[pin]
+        future: F,
+    }
+
+    impl<F> PinnedDrop for TrackedFuture<F> {
+        fn drop(this: Pin<&mut Self>) {
+            this.project().inner.untrack();
+        }
+    }
+}
+
+impl<F: Future> Future for TrackedFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.poll(cx)
+    }
+}
+
+pin_project! {
+    /// Future returned by [`TaskTracker::wait`].
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless polled"]
+    pub struct TaskTrackerWaitFuture<'a> {
+        inner: &'a Arc<Inner>,
+        #[pin]
+        notified: Notified<'a>,
+    }
+}
+
+fn is_done(inner: &Inner) -> bool {
+    inner.closed.load(Ordering::Acquire) && inner.count.load(Ordering::Acquire) == 0
+}
+
+impl<'a> Future for TaskTrackerWaitFuture<'a> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut this = self.project();
+
+        // The `notified` future was created before this exit condition was
+        // first checked, so a notification sent between that creation and
+        // now is not missed even though we only register as a waiter below.
+        if is_done(this.inner) {
+            return Poll::Ready(());
+        }
+
+        loop {
+            if this.notified.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            if is_done(this.inner) {
+                return Poll::Ready(());
+            }
+            // Spurious wakeup: re-register for the next notification.
+            this.notified.set(this.inner.notify.notified());
+        }
+    }
+}
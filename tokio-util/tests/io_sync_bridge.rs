@@ -0,0 +1,41 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "io")]
+#![cfg(feature = "rt")]
+
+use std::io::{Read, Write};
+
+use tokio_util::io::SyncIoBridge;
+
+#[tokio::test]
+async fn reads_from_the_wrapped_async_stream() {
+    let data = b"hello, world!".to_vec();
+    let result = tokio::task::spawn_blocking(move || {
+        let mut bridge = SyncIoBridge::new(&data[..]);
+        let mut out = Vec::new();
+        bridge.read_to_end(&mut out).unwrap();
+        out
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(result, b"hello, world!");
+}
+
+#[tokio::test]
+async fn writes_to_the_wrapped_async_stream() {
+    let (client, mut server) = tokio::io::duplex(64);
+
+    let write_task = tokio::task::spawn_blocking(move || {
+        let mut bridge = SyncIoBridge::new(client);
+        bridge.write_all(b"hello").unwrap();
+        bridge.flush().unwrap();
+    });
+
+    let mut buf = [0u8; 5];
+    tokio::io::AsyncReadExt::read_exact(&mut server, &mut buf)
+        .await
+        .unwrap();
+    assert_eq!(&buf, b"hello");
+
+    write_task.await.unwrap();
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "rt")]
+#![warn(rust_2018_idioms)]
+
+use std::time::Duration;
+
+use tokio::time::sleep;
+use tokio_util::task::TaskTracker;
+
+#[tokio::test]
+async fn wait_resolves_once_closed_and_empty() {
+    let tracker = TaskTracker::new();
+    assert!(tracker.is_empty());
+
+    // Closing an empty, open tracker lets `wait` resolve immediately.
+    tracker.close();
+    tracker.wait().await;
+}
+
+#[tokio::test]
+async fn wait_does_not_resolve_before_close() {
+    let tracker = TaskTracker::new();
+    tracker.spawn(async {});
+
+    let wait = tracker.wait();
+    tokio::pin!(wait);
+
+    // Not closed yet, so `wait` must not resolve even once the task finishes.
+    sleep(Duration::from_millis(10)).await;
+    assert!(
+        tokio::time::timeout(Duration::from_millis(10), &mut wait)
+            .await
+            .is_err()
+    );
+
+    tracker.close();
+    wait.await;
+}
+
+#[tokio::test]
+async fn wait_resolves_after_tracked_tasks_finish() {
+    let tracker = TaskTracker::new();
+
+    for _ in 0..5 {
+        tracker.spawn(async {
+            sleep(Duration::from_millis(10)).await;
+        });
+    }
+    tracker.close();
+
+    assert_eq!(tracker.len(), 5);
+    tracker.wait().await;
+    assert!(tracker.is_empty());
+}
+
+#[tokio::test]
+async fn spawn_blocking_is_tracked() {
+    let tracker = TaskTracker::new();
+    tracker.spawn_blocking(|| std::thread::sleep(Duration::from_millis(10)));
+    tracker.close();
+    tracker.wait().await;
+    assert!(tracker.is_empty());
+}
+
+#[tokio::test]
+async fn reopen_makes_wait_block_again() {
+    let tracker = TaskTracker::new();
+    tracker.close();
+    tracker.wait().await;
+
+    assert!(tracker.reopen());
+    assert!(!tracker.is_closed());
+
+    tracker.spawn(async {});
+    let wait = tracker.wait();
+    assert!(
+        tokio::time::timeout(Duration::from_millis(10), wait)
+            .await
+            .is_err()
+    );
+}
@@ -0,0 +1,89 @@
+use std::alloc::{self, Layout};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+/// The alignment required by `O_DIRECT` reads and writes on most Linux
+/// filesystems. Using a buffer aligned to less than this (or to less than
+/// the filesystem's logical block size, whichever is larger) will generally
+/// make `O_DIRECT` I/O fail with `EINVAL`.
+pub const DEFAULT_ALIGNMENT: usize = 4096;
+
+/// A fixed-size, heap-allocated buffer aligned to a given byte boundary.
+///
+/// `O_DIRECT` (set via [`OpenOptionsExt::custom_flags`]) bypasses the page
+/// cache, and in exchange requires that the buffer address, the buffer
+/// length, and the file offset all be aligned to the filesystem's block
+/// size. A plain `Vec<u8>` makes no such guarantee, so reads and writes
+/// against an `O_DIRECT` file need a buffer allocated through this type
+/// instead.
+///
+/// [`OpenOptionsExt::custom_flags`]: std::os::unix::fs::OpenOptionsExt::custom_flags
+///
+/// # Examples
+///
+/// ```
+/// use tokio::fs::{AlignedBuffer, DEFAULT_ALIGNMENT};
+///
+/// let mut buf = AlignedBuffer::new(4096, DEFAULT_ALIGNMENT);
+/// assert_eq!(buf.as_ptr() as usize % DEFAULT_ALIGNMENT, 0);
+/// buf[0] = 42;
+/// ```
+#[derive(Debug)]
+pub struct AlignedBuffer {
+    ptr: NonNull<u8>,
+    len: usize,
+    layout: Layout,
+}
+
+impl AlignedBuffer {
+    /// Allocates a new zeroed buffer of `len` bytes, aligned to `alignment`
+    /// bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alignment` is not a power of two. If the allocation fails,
+    /// this calls [`handle_alloc_error`](std::alloc::handle_alloc_error),
+    /// which aborts the process.
+    pub fn new(len: usize, alignment: usize) -> AlignedBuffer {
+        let layout = Layout::from_size_align(len.max(1), alignment)
+            .expect("invalid alignment for AlignedBuffer");
+
+        // SAFETY: `layout` has a non-zero size.
+        let ptr = unsafe { alloc::alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+
+        AlignedBuffer { ptr, len, layout }
+    }
+
+    /// Returns the alignment, in bytes, of this buffer.
+    pub fn alignment(&self) -> usize {
+        self.layout.align()
+    }
+}
+
+impl Deref for AlignedBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: `ptr` was allocated with `len` bytes and is valid for reads.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl DerefMut for AlignedBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: `ptr` was allocated with `len` bytes and is valid for writes.
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for AlignedBuffer {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` was allocated with `layout` and hasn't been freed yet.
+        unsafe { alloc::dealloc(self.ptr.as_ptr(), self.layout) }
+    }
+}
+
+// SAFETY: `AlignedBuffer` owns its allocation exclusively, just like `Vec<u8>`.
+unsafe impl Send for AlignedBuffer {}
+unsafe impl Sync for AlignedBuffer {}
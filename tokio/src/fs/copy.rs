@@ -1,4 +1,5 @@
 use crate::fs::asyncify;
+use std::io;
 use std::path::Path;
 
 /// Copies the contents of one file to another. This function will also copy the permission bits
@@ -9,6 +10,22 @@ use std::path::Path;
 ///
 /// [std]: fn@std::fs::copy
 ///
+/// There's no variant of this function that takes a progress callback: the
+/// whole copy is dispatched as a single [`spawn_blocking`] call running
+/// `std::fs::copy`, which on most platforms (Linux, for one) delegates to a
+/// single `copy_file_range`/`sendfile`/`fcopyfile` syscall rather than a
+/// userspace read/write loop, so there's no byte count to report progress
+/// from partway through. If you need incremental progress, open both ends
+/// yourself with [`File::open`]/[`File::create`] and drive the copy with
+/// [`io::copy`] through a wrapper [`AsyncWrite`] that counts bytes as they
+/// pass through, rather than through this function.
+///
+/// [`spawn_blocking`]: crate::task::spawn_blocking
+/// [`File::open`]: crate::fs::File::open
+/// [`File::create`]: crate::fs::File::create
+/// [`io::copy`]: crate::io::copy
+/// [`AsyncWrite`]: crate::io::AsyncWrite
+///
 /// # Examples
 ///
 /// ```no_run
@@ -20,7 +37,7 @@ use std::path::Path;
 /// # }
 /// ```
 
-pub async fn copy(from: impl AsRef<Path>, to: impl AsRef<Path>) -> Result<u64, std::io::Error> {
+pub async fn copy(from: impl AsRef<Path>, to: impl AsRef<Path>) -> io::Result<u64> {
     let from = from.as_ref().to_owned();
     let to = to.as_ref().to_owned();
     asyncify(|| std::fs::copy(from, to)).await
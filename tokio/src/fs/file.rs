@@ -254,7 +254,7 @@ impl File {
         asyncify(move || std.sync_all()).await
     }
 
-    /// This function is similar to `sync_all`, except that it may not
+    /// This function is similar to [`sync_all`], except that it may not
     /// synchronize file metadata to the filesystem.
     ///
     /// This is intended for use cases that must synchronize content, but don't
@@ -263,6 +263,8 @@ impl File {
     ///
     /// Note that some platforms may simply implement this in terms of `sync_all`.
     ///
+    /// [`sync_all`]: File::sync_all
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -363,6 +365,46 @@ impl File {
         }
     }
 
+    /// Preallocates `size` bytes of disk space for the file, starting from
+    /// the beginning.
+    ///
+    /// Unlike [`set_len`], which can leave a sparse file with holes that are
+    /// only actually allocated on disk when later written to, this asks the
+    /// filesystem to reserve the space up front via `fallocate(2)`. This is
+    /// useful for avoiding `ENOSPC` partway through writing a file whose
+    /// final size is known ahead of time. The file's length as reported by
+    /// [`metadata`] is extended to `size` if it was smaller.
+    ///
+    /// [`set_len`]: File::set_len
+    /// [`metadata`]: File::metadata
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::create("foo.txt").await?;
+    /// file.preallocate(1024 * 1024).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(target_os = "linux")]
+    pub async fn preallocate(&self, size: u64) -> io::Result<()> {
+        use std::os::unix::io::AsRawFd;
+
+        let std = self.std.clone();
+        asyncify(move || {
+            let ret = unsafe { libc::fallocate64(std.as_raw_fd(), 0, 0, size as libc::off64_t) };
+            if ret == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            }
+        })
+        .await
+    }
+
     /// Queries metadata about the underlying file.
     ///
     /// # Examples
@@ -383,10 +425,142 @@ impl File {
         asyncify(move || std.metadata()).await
     }
 
+    /// Reads a number of bytes starting from a given offset, without
+    /// affecting the file's internal cursor.
+    ///
+    /// This is an async version of [`std::os::unix::fs::FileExt::read_at`][std].
+    /// Because it takes `&self`, multiple positional reads can run
+    /// concurrently against the same `File`, unlike [`AsyncReadExt::read`]
+    /// which needs to seek the shared cursor first.
+    ///
+    /// [std]: std::os::unix::fs::FileExt::read_at
+    /// [`AsyncReadExt::read`]: crate::io::AsyncReadExt::read
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::open("foo.txt").await?;
+    /// let mut buf = vec![0; 16];
+    /// let n = file.read_at(&mut buf, 0).await?;
+    /// # let _ = n;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    pub async fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        use std::os::unix::fs::FileExt;
+
+        let std = self.std.clone();
+        let mut owned_buf = vec![0; buf.len()];
+        let n = asyncify(move || {
+            let n = std.read_at(&mut owned_buf, offset)?;
+            Ok((n, owned_buf))
+        })
+        .await
+        .map(|(n, owned_buf)| {
+            buf[..n].copy_from_slice(&owned_buf[..n]);
+            n
+        })?;
+        Ok(n)
+    }
+
+    /// Writes a number of bytes starting from a given offset, without
+    /// affecting the file's internal cursor.
+    ///
+    /// This is an async version of [`std::os::unix::fs::FileExt::write_at`][std].
+    /// Because it takes `&self`, multiple positional writes can run
+    /// concurrently against the same `File`, unlike [`AsyncWriteExt::write`]
+    /// which needs to seek the shared cursor first.
+    ///
+    /// [std]: std::os::unix::fs::FileExt::write_at
+    /// [`AsyncWriteExt::write`]: crate::io::AsyncWriteExt::write
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::create("foo.txt").await?;
+    /// let n = file.write_at(b"hello, world!", 0).await?;
+    /// # let _ = n;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    pub async fn write_at(&self, buf: &[u8], offset: u64) -> io::Result<usize> {
+        use std::os::unix::fs::FileExt;
+
+        let std = self.std.clone();
+        let owned_buf = buf.to_vec();
+        asyncify(move || std.write_at(&owned_buf, offset)).await
+    }
+
+    /// Writes an entire buffer starting from a given offset, without
+    /// affecting the file's internal cursor.
+    ///
+    /// This is an async version of
+    /// [`std::os::unix::fs::FileExt::write_all_at`][std]. Unlike
+    /// [`write_at`](File::write_at), which may perform a partial write, this
+    /// keeps retrying at successively later offsets until `buf` has been
+    /// written in full or an error (other than [`ErrorKind::Interrupted`])
+    /// occurs.
+    ///
+    /// [std]: std::os::unix::fs::FileExt::write_all_at
+    /// [`ErrorKind::Interrupted`]: std::io::ErrorKind::Interrupted
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let file = File::create("foo.txt").await?;
+    /// file.write_all_at(b"hello, world!", 0).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    pub async fn write_all_at(&self, buf: &[u8], offset: u64) -> io::Result<()> {
+        use std::os::unix::fs::FileExt;
+
+        let std = self.std.clone();
+        let owned_buf = buf.to_vec();
+        asyncify(move || {
+            let mut buf = &owned_buf[..];
+            let mut offset = offset;
+            while !buf.is_empty() {
+                match std.write_at(buf, offset) {
+                    Ok(0) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::WriteZero,
+                            "failed to write whole buffer",
+                        ));
+                    }
+                    Ok(n) => {
+                        buf = &buf[n..];
+                        offset += n as u64;
+                    }
+                    Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(())
+        })
+        .await
+    }
+
     /// Create a new `File` instance that shares the same underlying file handle
     /// as the existing `File` instance. Reads, writes, and seeks will affect both
     /// File instances simultaneously.
     ///
+    /// This duplicates the underlying OS file descriptor/handle, the same way
+    /// [`std::fs::File::try_clone`] does; it does not copy the file's
+    /// contents.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -490,6 +664,82 @@ impl File {
         let std = self.std.clone();
         asyncify(move || std.set_permissions(perm)).await
     }
+
+    /// Acquires an exclusive advisory lock on the file, blocking until it can
+    /// be acquired.
+    ///
+    /// This calls `flock(2)` with `LOCK_EX` under the hood. Since acquiring
+    /// the lock can block indefinitely, it is run on the blocking thread
+    /// pool, same as other `File` operations.
+    #[cfg(unix)]
+    pub async fn lock(&self) -> io::Result<()> {
+        let std = self.std.clone();
+        asyncify(move || sys::flock(&std, libc::LOCK_EX)).await
+    }
+
+    /// Acquires a shared (non-exclusive) advisory lock on the file, blocking
+    /// until it can be acquired.
+    ///
+    /// This calls `flock(2)` with `LOCK_SH` under the hood.
+    #[cfg(unix)]
+    pub async fn lock_shared(&self) -> io::Result<()> {
+        let std = self.std.clone();
+        asyncify(move || sys::flock(&std, libc::LOCK_SH)).await
+    }
+
+    /// Tries to acquire an exclusive advisory lock on the file.
+    ///
+    /// Returns `Err` with [`ErrorKind::WouldBlock`] if another handle
+    /// currently holds a lock, rather than waiting for it to be released.
+    /// Since this never blocks, it runs directly rather than via the
+    /// blocking thread pool.
+    ///
+    /// [`ErrorKind::WouldBlock`]: std::io::ErrorKind::WouldBlock
+    #[cfg(unix)]
+    pub fn try_lock(&self) -> io::Result<()> {
+        sys::flock(&self.std, libc::LOCK_EX | libc::LOCK_NB)
+    }
+
+    /// Tries to acquire a shared (non-exclusive) advisory lock on the file.
+    ///
+    /// Returns `Err` with [`ErrorKind::WouldBlock`] if another handle
+    /// currently holds an exclusive lock, rather than waiting for it to be
+    /// released. Since this never blocks, it runs directly rather than via
+    /// the blocking thread pool.
+    ///
+    /// [`ErrorKind::WouldBlock`]: std::io::ErrorKind::WouldBlock
+    #[cfg(unix)]
+    pub fn try_lock_shared(&self) -> io::Result<()> {
+        sys::flock(&self.std, libc::LOCK_SH | libc::LOCK_NB)
+    }
+
+    /// Releases all locks held on the file via this handle.
+    ///
+    /// This calls `flock(2)` with `LOCK_UN` under the hood.
+    #[cfg(unix)]
+    pub fn unlock(&self) -> io::Result<()> {
+        sys::flock(&self.std, libc::LOCK_UN)
+    }
+}
+
+#[cfg(unix)]
+mod sys {
+    use super::StdFile;
+    use std::io;
+    use std::os::unix::io::AsRawFd;
+
+    // `std::fs::File::{lock, try_lock, unlock, ...}` aren't usable here: they
+    // were only stabilized in Rust 1.89, well past this crate's MSRV. `flock`
+    // on the raw fd gives the same advisory-locking semantics without the
+    // newer std dependency.
+    pub(super) fn flock(file: &StdFile, flag: libc::c_int) -> io::Result<()> {
+        let ret = unsafe { libc::flock(file.as_raw_fd(), flag) };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
 }
 
 impl AsyncRead for File {
@@ -688,6 +938,75 @@ impl AsyncWrite for File {
         }
     }
 
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let me = self.get_mut();
+        let inner = me.inner.get_mut();
+
+        if let Some(e) = inner.last_write_err.take() {
+            return Ready(Err(e.into()));
+        }
+
+        loop {
+            match inner.state {
+                Idle(ref mut buf_cell) => {
+                    let mut buf = buf_cell.take().unwrap();
+
+                    let seek = if !buf.is_empty() {
+                        Some(SeekFrom::Current(buf.discard_read()))
+                    } else {
+                        None
+                    };
+
+                    let n = buf.copy_from_bufs(bufs);
+                    let std = me.std.clone();
+
+                    inner.state = Busy(spawn_blocking(move || {
+                        let res = if let Some(seek) = seek {
+                            (&*std).seek(seek).and_then(|_| buf.write_to(&mut &*std))
+                        } else {
+                            buf.write_to(&mut &*std)
+                        };
+
+                        (Operation::Write(res), buf)
+                    }));
+
+                    return Ready(Ok(n));
+                }
+                Busy(ref mut rx) => {
+                    let (op, buf) = ready!(Pin::new(rx).poll(cx))?;
+                    inner.state = Idle(Some(buf));
+
+                    match op {
+                        Operation::Read(_) => {
+                            // We don't care about the result here. The fact
+                            // that the cursor has advanced will be reflected in
+                            // the next iteration of the loop
+                            continue;
+                        }
+                        Operation::Write(res) => {
+                            // If the previous write was successful, continue.
+                            // Otherwise, error.
+                            res?;
+                            continue;
+                        }
+                        Operation::Seek(_) => {
+                            // Ignore the seek
+                            continue;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
     fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
         let inner = self.inner.get_mut();
         inner.poll_flush(cx)
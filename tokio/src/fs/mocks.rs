@@ -33,6 +33,13 @@ mock! {
         pub fn sync_all(&self) -> io::Result<()>;
         pub fn sync_data(&self) -> io::Result<()>;
         pub fn try_clone(&self) -> io::Result<Self>;
+        pub fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize>;
+        pub fn write_at(&self, buf: &[u8], offset: u64) -> io::Result<usize>;
+        pub fn lock(&self) -> io::Result<()>;
+        pub fn lock_shared(&self) -> io::Result<()>;
+        pub fn try_lock(&self) -> io::Result<()>;
+        pub fn try_lock_shared(&self) -> io::Result<()>;
+        pub fn unlock(&self) -> io::Result<()>;
     }
     #[cfg(windows)]
     impl std::os::windows::io::AsRawHandle for File {
@@ -13,6 +13,16 @@
 //! necessary, this allows the runtime to convert the current thread from a
 //! *worker* to a *backup* thread, where blocking is acceptable.
 //!
+//! These adapters share the runtime's single blocking pool with
+//! [`spawn_blocking`] rather than running on a pool of their own — a
+//! dedicated pool would just mean two sets of idle threads to tune and
+//! size instead of one. Its upper bound is controlled by
+//! [`Builder::max_blocking_threads`], which bounds `fs` operations the same
+//! way it bounds any other blocking work.
+//!
+//! [`spawn_blocking`]: crate::task::spawn_blocking
+//! [`Builder::max_blocking_threads`]: crate::runtime::Builder::max_blocking_threads
+//!
 //! ## Usage
 //!
 //! Where possible, users should prefer the provided asynchronous-specific
@@ -23,6 +33,52 @@
 //! to a *backup* thread immediately.
 //!
 //! [`AsyncRead`]: trait@crate::io::AsyncRead
+//!
+//! ## Filesystem watching
+//!
+//! This module does not provide a way to watch a path for changes (e.g. via
+//! `inotify`, `FSEvents`, or `ReadDirectoryChangesW`). That's a platform
+//! abstraction problem in its own right, with its own maintenance burden and
+//! release cadence, so it's deliberately kept out of Tokio core the same way
+//! TLS and HTTP are: the [`notify`] crate covers it and already knows how to
+//! hand events to a Tokio channel from its background watcher thread.
+//!
+//! [`notify`]: https://docs.rs/notify
+//!
+//! ## Recursive directory walking
+//!
+//! [`read_dir`] only lists the immediate children of a directory, by
+//! design: it maps directly onto [`std::fs::read_dir`], and a recursive
+//! walk needs policy decisions (following symlinks, skipping mount points,
+//! traversal order) that don't have one right answer. Crates like
+//! [`async-walkdir`] build that policy on top of [`read_dir`].
+//!
+//! [`async-walkdir`]: https://docs.rs/async-walkdir
+//!
+//! ## io_uring
+//!
+//! Operations in this module go through [`spawn_blocking`] onto the
+//! blocking pool rather than being submitted to `io_uring` on Linux. Doing
+//! the latter well is not a matter of swapping the backend under the
+//! existing API: `io_uring`'s completion model requires the kernel to own
+//! the buffer for the duration of an in-flight read or write, which is
+//! incompatible with the borrowed `&mut [u8]` buffers `AsyncRead` and
+//! `std::fs::File`-alike methods take here — the caller could move or drop
+//! the buffer while the kernel is still writing into it. Supporting
+//! `io_uring` properly means an owned-buffer API (`Vec<u8>` or similar
+//! passed by value and handed back on completion), which is a different
+//! shape of API, not an additional feature flag on this one. The
+//! [`tokio-uring`] crate builds that owned-buffer API and its own runtime
+//! integration on top of Tokio's core primitives rather than this module
+//! growing a second I/O model internally.
+//!
+//! [`spawn_blocking`]: crate::task::spawn_blocking
+//! [`tokio-uring`]: https://docs.rs/tokio-uring
+
+#[cfg(unix)]
+mod aligned_buffer;
+#[cfg(unix)]
+pub use self::aligned_buffer::{AlignedBuffer, DEFAULT_ALIGNMENT};
 
 mod canonicalize;
 pub use self::canonicalize::canonicalize;
@@ -78,6 +134,9 @@ pub use self::set_permissions::set_permissions;
 mod symlink_metadata;
 pub use self::symlink_metadata::symlink_metadata;
 
+mod try_exists;
+pub use self::try_exists::try_exists;
+
 mod write;
 pub use self::write::write;
 
@@ -438,6 +438,14 @@ feature! {
         /// Custom flags can only set flags, not remove flags set by Rusts options.
         /// This options overwrites any previously set custom flags.
         ///
+        /// This is also how to open a file with `O_DIRECT` on Linux, which
+        /// bypasses the page cache; reads and writes against such a file
+        /// must use a buffer allocated with [`AlignedBuffer`], since the
+        /// kernel requires the buffer address, length, and file offset to
+        /// all be aligned to the filesystem's block size.
+        ///
+        /// [`AlignedBuffer`]: crate::fs::AlignedBuffer
+        ///
         /// # Examples
         ///
         /// ```no_run
@@ -653,6 +661,25 @@ feature! {
 }
 
 impl From<StdOpenOptions> for OpenOptions {
+    /// Converts a [`std::fs::OpenOptions`] into a Tokio `OpenOptions`.
+    ///
+    /// This allows any platform-specific flag not exposed directly on this
+    /// builder (via the `unix`/`windows` extension traits above) to still be
+    /// configured by building it on [`std::fs::OpenOptions`] first and then
+    /// converting it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::OpenOptions;
+    ///
+    /// # async fn dox() -> std::io::Result<()> {
+    /// let std_options = std::fs::OpenOptions::new().read(true).append(true).clone();
+    /// let options = OpenOptions::from(std_options);
+    /// let file = options.open("foo.txt").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     fn from(options: StdOpenOptions) -> OpenOptions {
         OpenOptions(options)
     }
@@ -26,7 +26,16 @@ use crate::blocking::JoinHandle;
 /// This operation is implemented by running the equivalent blocking
 /// operation on a separate thread pool using [`spawn_blocking`].
 ///
+/// Entries are read one at a time from that thread pool via
+/// [`ReadDir::next_entry`]/[`ReadDir::poll_next_entry`], so the [`DirEntry`]
+/// values making up the listing arrive incrementally rather than all at
+/// once, which keeps memory use flat for directories with very many files.
+///
+/// This only lists `path`'s immediate children; see the [module-level docs]
+/// for why a recursive walk isn't built on top of it here.
+///
 /// [`spawn_blocking`]: crate::task::spawn_blocking
+/// [module-level docs]: crate::fs#recursive-directory-walking
 pub async fn read_dir(path: impl AsRef<Path>) -> io::Result<ReadDir> {
     let path = path.as_ref().to_owned();
     let std = asyncify(|| std::fs::read_dir(path)).await?;
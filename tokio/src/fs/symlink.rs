@@ -10,6 +10,18 @@ use std::path::Path;
 /// This is an async version of [`std::os::unix::fs::symlink`][std]
 ///
 /// [std]: std::os::unix::fs::symlink
+///
+/// # Examples
+///
+/// ```no_run
+/// use tokio::fs;
+///
+/// #[tokio::main]
+/// async fn main() -> std::io::Result<()> {
+///     fs::symlink("a.txt", "b.txt").await?; // Symlink a.txt to b.txt
+///     Ok(())
+/// }
+/// ```
 pub async fn symlink(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> io::Result<()> {
     let src = src.as_ref().to_owned();
     let dst = dst.as_ref().to_owned();
@@ -0,0 +1,40 @@
+use crate::fs::asyncify;
+
+use std::io;
+use std::path::Path;
+
+/// Returns `Ok(true)` if the path points at an existing entity.
+///
+/// This is an async version of checking [`std::path::Path::try_exists`][std].
+///
+/// This function will traverse symbolic links to query information about the
+/// destination file. In case of broken symbolic links this will return
+/// `Ok(false)`.
+///
+/// It calls [`metadata`] and turns only a `NotFound` error into
+/// `Ok(false)`. Every other I/O error (such as a permissions error on one
+/// of the parent directories) is returned as an `Err`, since it says
+/// nothing about whether the path exists.
+///
+/// [std]: std::path::Path::try_exists
+/// [`metadata`]: crate::fs::metadata
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::fs;
+///
+/// #[tokio::main]
+/// async fn main() -> std::io::Result<()> {
+///     assert!(!fs::try_exists("does_not_exist.txt").await?);
+///     Ok(())
+/// }
+/// ```
+pub async fn try_exists(path: impl AsRef<Path>) -> io::Result<bool> {
+    let path = path.as_ref().to_owned();
+    match asyncify(move || std::fs::metadata(path)).await {
+        Ok(_) => Ok(true),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(error) => Err(error),
+    }
+}
@@ -225,6 +225,23 @@ impl Buf {
         n
     }
 
+    pub(crate) fn copy_from_bufs(&mut self, bufs: &[io::IoSlice<'_>]) -> usize {
+        assert!(self.is_empty());
+
+        let mut rem = MAX_BUF;
+        for buf in bufs {
+            if rem == 0 {
+                break;
+            }
+
+            let n = cmp::min(buf.len(), rem);
+            self.buf.extend_from_slice(&buf[..n]);
+            rem -= n;
+        }
+
+        self.buf.len()
+    }
+
     pub(crate) fn bytes(&self) -> &[u8] {
         &self.buf[self.pos..]
     }
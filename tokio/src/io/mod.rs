@@ -132,6 +132,28 @@
 //! other words, these types must never block the thread, and instead the
 //! current task is notified when the I/O resource is ready.
 //!
+//! ## Owned-buffer (completion-based) I/O
+//!
+//! [`AsyncRead`] and [`AsyncWrite`] take a borrowed `&mut [u8]` buffer
+//! ([`ReadBuf`]) because they model *readiness-based* I/O: `poll_read` and
+//! `poll_write` are called once the OS has already said data can move
+//! without blocking, so the buffer only needs to be valid for that single,
+//! synchronous call. There is no `read_owned(BytesMut) -> impl Future<Output
+//! = (BytesMut, Result<usize>)>` or `write_owned(Bytes)` pair on
+//! [`AsyncReadExt`]/[`AsyncWriteExt`], because *completion-based* backends
+//! (`io_uring`, Windows IOCP) need the opposite shape: the buffer must be
+//! handed to the kernel and stay alive, unmoved, until the kernel signals
+//! completion, which can span multiple polls and outlive any one stack
+//! frame. Bolting an owned-buffer method onto `AsyncReadExt` wouldn't give
+//! completion-based backends what they need underneath — `AsyncRead`'s
+//! `Pin<&mut Self>` receiver and per-poll `&mut [u8]` buffer are the
+//! readiness model baked into the trait itself. [`tokio-uring`] defines its
+//! own `AsyncRead`/`AsyncWrite`-alike traits built around owned buffers
+//! rather than retrofitting this module's.
+//!
+//! [`ReadBuf`]: crate::io::ReadBuf
+//! [`tokio-uring`]: https://docs.rs/tokio-uring
+//!
 //! ## Conversion to and from Sink/Stream
 //!
 //! It is often convenient to encapsulate the reading and writing of
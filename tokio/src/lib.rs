@@ -234,7 +234,15 @@
 //! * [`tokio::process`], for spawning and managing child processes (enabled by
 //!   the "process" feature flag).
 //!
+//! Frame-based protocols built on top of `AsyncRead`/`AsyncWrite` (a `Framed`
+//! transport, the `Encoder`/`Decoder` traits, and ready-made codecs such as
+//! `LengthDelimitedCodec`) live in the [`tokio-util`] crate's `codec` module
+//! rather than here. Keeping that layer out of `tokio` itself lets codec
+//! implementations and the wire formats they support evolve independently
+//! of this crate's release cadence.
+//!
 //! [`tokio::io`]: crate::io
+//! [`tokio-util`]: https://docs.rs/tokio-util
 //! [`AsyncRead`]: crate::io::AsyncRead
 //! [`AsyncWrite`]: crate::io::AsyncWrite
 //! [`AsyncBufRead`]: crate::io::AsyncBufRead
@@ -321,6 +329,25 @@
 //! _Note: `AsyncRead` and `AsyncWrite` traits do not require any features and are
 //! always available._
 //!
+//! ### Wasm support
+//!
+//! Tokio has limited support for the `wasm32-unknown-unknown` and `wasm32-wasi`
+//! targets, covering only `--no-default-features` builds with some subset of
+//! `rt`, `sync`, `macros`, and `time` enabled — `time` is limited to whatever
+//! monotonic clock the host provides `std::time::Instant` through, and
+//! `rt-multi-thread` is unavailable for the same reason `std::thread` is
+//! unavailable: there is no way to spawn OS threads.
+//!
+//! The `fs`, `process`, and `signal` modules, and everything in `net`
+//! except the [`ToSocketAddrs`](net::ToSocketAddrs) trait, are compiled out
+//! on these targets, since the underlying OS facilities they rely
+//! on (`mio`-based polling, process spawning, Unix/Windows signal delivery)
+//! have no wasm equivalent. Enabling the corresponding `net`/`fs`/`process`/
+//! `signal` *feature flags* on wasm is harmless — `mio`, the dependency
+//! those features would otherwise pull in, is itself excluded from the
+//! dependency graph on `wasm32` — but it buys nothing either, since there
+//! are no items left for the feature to turn on.
+//!
 //! ### Internal features
 //!
 //! These features do not expose any new API, but influence internal
@@ -338,8 +365,26 @@
 //! `rustc` when compiling. This is easiest done using the `RUSTFLAGS` env variable:
 //! `RUSTFLAGS="--cfg tokio_unstable"`.
 //!
-//! - `tracing`: Enables tracing events.
-//!
+//! - `tracing`: Enables tracing events. This is the integration point for
+//!   external instrumentation consoles: each spawned task and resource is
+//!   instrumented with a [`tracing`] span carrying scheduler-internal fields
+//!   (poll times, wakers, task IDs). A separate process-level subscriber can
+//!   consume those spans and serve them over whatever wire protocol it
+//!   chooses (for example, a gRPC endpoint for a console UI). Tokio itself
+//!   does not embed such a server, so that the wire format and transport can
+//!   evolve independently of this crate and so that `tokio` does not carry a
+//!   gRPC/protobuf dependency for applications that don't use it.
+//!
+//! There is no separate `rt-instrument` feature that streams task lifecycle
+//! events (spawn, poll durations, wakes, drops) over a socket directly from
+//! the scheduler, task harness, and blocking pool. The [`tracing`] spans
+//! enabled by the `tracing` feature already carry that information at the
+//! point it's generated; the [`console-subscriber`] crate builds the
+//! gRPC wire protocol and live console on top of those spans rather than
+//! tokio shipping a second, built-in path to the same data.
+//!
+//! [`tracing`]: https://docs.rs/tracing
+//! [`console-subscriber`]: https://docs.rs/console-subscriber
 //! [feature flags]: https://doc.rust-lang.org/cargo/reference/manifest.html#the-features-section
 
 // Includes re-exports used by macros.
@@ -473,13 +518,13 @@ cfg_macros! {
     pub use tokio_macros::select_priv_declare_output_enum;
 
     cfg_rt! {
-        #[cfg(feature = "rt-multi-thread")]
+        #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
         #[cfg(not(test))] // Work around for rust-lang/rust#62127
         #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
         #[doc(inline)]
         pub use tokio_macros::main;
 
-        #[cfg(feature = "rt-multi-thread")]
+        #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
         #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
         #[doc(inline)]
         pub use tokio_macros::test;
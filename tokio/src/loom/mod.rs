@@ -1,14 +1,18 @@
-//! This module abstracts over `loom` and `std::sync` depending on whether we
-//! are running tests or not.
+//! This module abstracts over `loom` and `std::sync` depending on whether
+//! the whole build is compiled with `--cfg loom`. This flag is not limited
+//! to Tokio's own test suite: a downstream crate that builds with `--cfg
+//! loom` (for example, to run its own `loom` model checks against code that
+//! uses `tokio::sync`) also gets Tokio's real primitives swapped to their
+//! `loom`-aware equivalents, rather than an inert stub.
 
 #![allow(unused)]
 
-#[cfg(not(all(test, loom)))]
+#[cfg(not(loom))]
 mod std;
-#[cfg(not(all(test, loom)))]
+#[cfg(not(loom))]
 pub(crate) use self::std::*;
 
-#[cfg(all(test, loom))]
+#[cfg(loom)]
 mod mocked;
-#[cfg(all(test, loom))]
+#[cfg(loom)]
 pub(crate) use self::mocked::*;
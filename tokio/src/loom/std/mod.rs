@@ -82,12 +82,12 @@ pub(crate) mod sync {
 }
 
 pub(crate) mod sys {
-    #[cfg(feature = "rt-multi-thread")]
+    #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
     pub(crate) fn num_cpus() -> usize {
         usize::max(1, num_cpus::get())
     }
 
-    #[cfg(not(feature = "rt-multi-thread"))]
+    #[cfg(any(not(feature = "rt-multi-thread"), target_arch = "wasm32"))]
     pub(crate) fn num_cpus() -> usize {
         1
     }
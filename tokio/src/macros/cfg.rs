@@ -48,7 +48,7 @@ macro_rules! cfg_atomic_waker_impl {
 macro_rules! cfg_fs {
     ($($item:item)*) => {
         $(
-            #[cfg(feature = "fs")]
+            #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
             #[cfg_attr(docsrs, doc(cfg(feature = "fs")))]
             $item
         )*
@@ -64,10 +64,13 @@ macro_rules! cfg_io_blocking {
 macro_rules! cfg_io_driver {
     ($($item:item)*) => {
         $(
-            #[cfg(any(
-                feature = "net",
-                feature = "process",
-                all(unix, feature = "signal"),
+            #[cfg(all(
+                not(target_arch = "wasm32"),
+                any(
+                    feature = "net",
+                    feature = "process",
+                    all(unix, feature = "signal"),
+                ),
             ))]
             #[cfg_attr(docsrs, doc(cfg(any(
                 feature = "net",
@@ -82,10 +85,13 @@ macro_rules! cfg_io_driver {
 macro_rules! cfg_io_driver_impl {
     ( $( $item:item )* ) => {
         $(
-            #[cfg(any(
-                feature = "net",
-                feature = "process",
-                all(unix, feature = "signal"),
+            #[cfg(all(
+                not(target_arch = "wasm32"),
+                any(
+                    feature = "net",
+                    feature = "process",
+                    all(unix, feature = "signal"),
+                ),
             ))]
             $item
         )*
@@ -95,10 +101,13 @@ macro_rules! cfg_io_driver_impl {
 macro_rules! cfg_not_io_driver {
     ($($item:item)*) => {
         $(
-            #[cfg(not(any(
-                feature = "net",
-                feature = "process",
-                all(unix, feature = "signal"),
+            #[cfg(not(all(
+                not(target_arch = "wasm32"),
+                any(
+                    feature = "net",
+                    feature = "process",
+                    all(unix, feature = "signal"),
+                ),
             )))]
             $item
         )*
@@ -165,7 +174,7 @@ macro_rules! cfg_macros {
 macro_rules! cfg_net {
     ($($item:item)*) => {
         $(
-            #[cfg(feature = "net")]
+            #[cfg(all(feature = "net", not(target_arch = "wasm32")))]
             #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
             $item
         )*
@@ -175,7 +184,7 @@ macro_rules! cfg_net {
 macro_rules! cfg_net_unix {
     ($($item:item)*) => {
         $(
-            #[cfg(all(unix, feature = "net"))]
+            #[cfg(all(unix, feature = "net", not(target_arch = "wasm32")))]
             #[cfg_attr(docsrs, doc(cfg(feature = "net")))]
             $item
         )*
@@ -198,6 +207,7 @@ macro_rules! cfg_process {
             #[cfg(feature = "process")]
             #[cfg_attr(docsrs, doc(cfg(feature = "process")))]
             #[cfg(not(loom))]
+            #[cfg(not(target_arch = "wasm32"))]
             $item
         )*
     }
@@ -226,6 +236,7 @@ macro_rules! cfg_signal {
             #[cfg(feature = "signal")]
             #[cfg_attr(docsrs, doc(cfg(feature = "signal")))]
             #[cfg(not(loom))]
+            #[cfg(not(target_arch = "wasm32"))]
             $item
         )*
     }
@@ -285,7 +296,7 @@ macro_rules! cfg_not_rt {
 macro_rules! cfg_rt_multi_thread {
     ($($item:item)*) => {
         $(
-            #[cfg(feature = "rt-multi-thread")]
+            #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
             #[cfg_attr(docsrs, doc(cfg(feature = "rt-multi-thread")))]
             $item
         )*
@@ -294,7 +305,7 @@ macro_rules! cfg_rt_multi_thread {
 
 macro_rules! cfg_not_rt_multi_thread {
     ($($item:item)*) => {
-        $( #[cfg(not(feature = "rt-multi-thread"))] $item )*
+        $( #[cfg(any(not(feature = "rt-multi-thread"), target_arch = "wasm32"))] $item )*
     }
 }
 
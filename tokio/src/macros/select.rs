@@ -318,6 +318,43 @@
 /// }
 /// ```
 ///
+/// A common use of `biased;` is checking a shutdown signal before a data
+/// channel on every iteration of a processing loop, so that a pending
+/// shutdown is always honored even if the data channel is always ready.
+///
+/// ```
+/// use tokio::sync::{mpsc, oneshot};
+///
+/// async fn run(mut data: mpsc::Receiver<u32>, mut shutdown: oneshot::Receiver<()>) {
+///     loop {
+///         tokio::select! {
+///             biased;
+///
+///             _ = &mut shutdown => {
+///                 break;
+///             }
+///             Some(_item) = data.recv() => {
+///                 // process `_item`
+///             }
+///             else => break,
+///         }
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let (data_tx, data_rx) = mpsc::channel(8);
+///     let (shutdown_tx, shutdown_rx) = oneshot::channel();
+///
+///     let handle = tokio::spawn(run(data_rx, shutdown_rx));
+///
+///     data_tx.send(1).await.unwrap();
+///     shutdown_tx.send(()).unwrap();
+///
+///     handle.await.unwrap();
+/// }
+/// ```
+///
 /// ## Avoid racy `if` preconditions
 ///
 /// Given that `if` preconditions are used to disable `select!` branches, some
@@ -1,4 +1,4 @@
-#[cfg(all(loom, test))]
+#[cfg(loom)]
 macro_rules! thread_local {
     ($($tts:tt)+) => { loom::thread_local!{ $($tts)+ } }
 }
@@ -8,6 +8,16 @@ use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV
 /// # DNS
 ///
 /// Implementations of `ToSocketAddrs` for string types require a DNS lookup.
+/// That lookup always runs `std::net::ToSocketAddrs` (i.e. the system
+/// resolver, `getaddrinfo` on Unix) on the blocking pool; there's no hook to
+/// swap in a different resolver, such as one with its own timeout and
+/// caching behavior, because `ToSocketAddrsPriv` is a sealed implementation
+/// detail rather than an extension point. If you need that, resolve the
+/// hostname yourself with the resolver of your choice and pass the
+/// resulting [`SocketAddr`](std::net::SocketAddr) (or a slice of them)
+/// to functions like [`TcpStream::connect`](crate::net::TcpStream::connect)
+/// instead of a hostname string — every `SocketAddr` impl of this trait
+/// resolves immediately with no blocking-pool hop at all.
 ///
 /// # Calling
 ///
@@ -13,6 +13,8 @@
 //! Unix Domain Stream Socket **(available on Unix only)**
 //! * [`UnixDatagram`] provides functionality for communication
 //! over Unix Domain Datagram Socket **(available on Unix only)**
+//! * [`named_pipe`] provides functionality for communication over a
+//! Windows Named Pipe **(available on Windows only)**
 
 //!
 //! [`TcpListener`]: TcpListener
@@ -21,6 +23,7 @@
 //! [`UnixListener`]: UnixListener
 //! [`UnixStream`]: UnixStream
 //! [`UnixDatagram`]: UnixDatagram
+//! [`named_pipe`]: windows::named_pipe
 
 mod addr;
 #[cfg(feature = "net")]
@@ -37,7 +40,7 @@ cfg_net! {
     pub use tcp::stream::TcpStream;
 
     mod udp;
-    pub use udp::UdpSocket;
+    pub use udp::{SendMsg, UdpSocket};
 }
 
 cfg_net_unix! {
@@ -50,3 +53,10 @@ cfg_net_unix! {
 cfg_net_windows! {
     pub mod windows;
 }
+
+cfg_net! {
+    #[cfg(target_os = "linux")]
+    mod vsock;
+    #[cfg(target_os = "linux")]
+    pub use vsock::{VsockAddr, VsockListener, VsockStream};
+}
@@ -134,6 +134,17 @@ impl TcpListener {
     ///
     /// [`TcpStream`]: struct@crate::net::TcpStream
     ///
+    /// This does not re-enter the OS's readiness poll on every call: the
+    /// registration caches a readable edge until `accept()` actually sees
+    /// `WouldBlock`, so a plain `loop { listener.accept().await? }` already
+    /// drains a full backlog from one readiness notification without extra
+    /// syscalls in between — there's no separate batch-accept method needed
+    /// to get that. For turning that loop into a named [`Stream`], see
+    /// [`TcpListenerStream`].
+    ///
+    /// [`Stream`]: https://docs.rs/futures/latest/futures/stream/trait.Stream.html
+    /// [`TcpListenerStream`]: https://docs.rs/tokio-stream/0.1/tokio_stream/wrappers/struct.TcpListenerStream.html
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -164,6 +175,42 @@ impl TcpListener {
         Ok((stream, addr))
     }
 
+    /// Accepts a new incoming connection and runs `configure` on it before
+    /// returning, so options like `set_nodelay` are applied before the
+    /// caller can observe the stream.
+    ///
+    /// This is equivalent to calling [`accept`](TcpListener::accept) and then
+    /// `configure`, except that if `configure` returns an error the
+    /// connection is dropped instead of being handed back to the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::TcpListener;
+    ///
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let listener = TcpListener::bind("127.0.0.1:8080").await?;
+    ///
+    ///     let (socket, addr) = listener
+    ///         .accept_with(|stream| stream.set_nodelay(true))
+    ///         .await?;
+    ///     println!("new client: {:?}", addr);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn accept_with(
+        &self,
+        configure: impl FnOnce(&TcpStream) -> io::Result<()>,
+    ) -> io::Result<(TcpStream, SocketAddr)> {
+        let (stream, addr) = self.accept().await?;
+        configure(&stream)?;
+        Ok((stream, addr))
+    }
+
     /// Polls to accept a new incoming connection to this listener.
     ///
     /// If there is no connection to accept, `Poll::Pending` is returned and the
@@ -155,6 +155,54 @@ impl TcpSocket {
         Ok(TcpSocket { inner })
     }
 
+    /// Create a new IPv4 Multipath TCP (MPTCP) socket.
+    ///
+    /// Calls `socket(2)` with `AF_INET`, `SOCK_STREAM`, and `IPPROTO_MPTCP`,
+    /// letting a connection spread traffic across multiple network paths
+    /// (e.g. cellular and Wi-Fi) for resilience and throughput. If the
+    /// running kernel doesn't support MPTCP, this falls back to a plain TCP
+    /// socket via [`new_v4`](TcpSocket::new_v4) rather than failing, since
+    /// MPTCP is a performance optimization and plain TCP is always a
+    /// correct substitute.
+    #[cfg(target_os = "linux")]
+    pub fn new_v4_mptcp() -> io::Result<TcpSocket> {
+        TcpSocket::new_mptcp(libc::AF_INET)
+    }
+
+    /// Create a new IPv6 Multipath TCP (MPTCP) socket.
+    ///
+    /// See [`new_v4_mptcp`](TcpSocket::new_v4_mptcp) for details, including
+    /// the fallback behavior when the kernel doesn't support MPTCP.
+    #[cfg(target_os = "linux")]
+    pub fn new_v6_mptcp() -> io::Result<TcpSocket> {
+        TcpSocket::new_mptcp(libc::AF_INET6)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn new_mptcp(domain: libc::c_int) -> io::Result<TcpSocket> {
+        let fd = unsafe {
+            libc::socket(
+                domain,
+                libc::SOCK_STREAM | libc::SOCK_NONBLOCK | libc::SOCK_CLOEXEC,
+                libc::IPPROTO_MPTCP,
+            )
+        };
+        if fd >= 0 {
+            return Ok(unsafe { TcpSocket::from_raw_fd(fd) });
+        }
+
+        let err = io::Error::last_os_error();
+        if err.raw_os_error() == Some(libc::EPROTONOSUPPORT) {
+            if domain == libc::AF_INET {
+                TcpSocket::new_v4()
+            } else {
+                TcpSocket::new_v6()
+            }
+        } else {
+            Err(err)
+        }
+    }
+
     /// Allow the socket to bind to an in-use address.
     ///
     /// Behavior is platform specific. Refer to the target platform's
@@ -211,6 +259,80 @@ impl TcpSocket {
         self.inner.get_reuseaddr()
     }
 
+    /// Binds this socket to a particular network interface, via the
+    /// `SO_BINDTODEVICE` option.
+    ///
+    /// Once bound, the socket can only send and receive traffic over that
+    /// interface, which is useful for multi-homed hosts and container
+    /// sidecars that must not leak traffic onto the wrong network.
+    ///
+    /// Pass `None` to clear a previously set binding.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::TcpSocket;
+    ///
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let socket = TcpSocket::new_v4()?;
+    ///     socket.bind_device(Some(b"eth0"))?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(target_os = "linux")]
+    pub fn bind_device(&self, interface: Option<&[u8]>) -> io::Result<()> {
+        let (ptr, len) = match interface {
+            Some(name) => (name.as_ptr() as *const libc::c_void, name.len()),
+            None => (std::ptr::null(), 0),
+        };
+        let ret = unsafe {
+            libc::setsockopt(
+                self.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_BINDTODEVICE,
+                ptr,
+                len as libc::socklen_t,
+            )
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    /// Retrieves the value set for `SO_BINDTODEVICE` on this socket, i.e. the
+    /// name of the interface this socket is bound to, if any.
+    #[cfg(target_os = "linux")]
+    pub fn device(&self) -> io::Result<Option<Vec<u8>>> {
+        let mut buf = [0u8; libc::IFNAMSIZ];
+        let mut len = buf.len() as libc::socklen_t;
+        let ret = unsafe {
+            libc::getsockopt(
+                self.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_BINDTODEVICE,
+                buf.as_mut_ptr() as *mut libc::c_void,
+                &mut len,
+            )
+        };
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if len == 0 {
+            return Ok(None);
+        }
+        // The kernel returns the name NUL-terminated; trim it off.
+        let end = buf[..len as usize]
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(len as usize);
+        Ok(Some(buf[..end].to_vec()))
+    }
+
     /// Allow the socket to bind to an in-use port. Only available for unix systems
     /// (excluding Solaris & Illumos).
     ///
@@ -280,6 +402,112 @@ impl TcpSocket {
         self.inner.get_reuseport()
     }
 
+    /// Sets the value of the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// If set, an IPv6 socket only accepts IPv6 traffic, disabling the
+    /// dual-stack behavior that lets it also accept IPv4 connections mapped
+    /// into IPv6 addresses. Has no effect on IPv4 sockets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::TcpSocket;
+    ///
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let socket = TcpSocket::new_v6()?;
+    ///     socket.set_only_v6(true)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(unix)]
+    pub fn set_only_v6(&self, only_v6: bool) -> io::Result<()> {
+        let value: libc::c_int = only_v6 as libc::c_int;
+        let ret = unsafe {
+            libc::setsockopt(
+                self.as_raw_fd(),
+                libc::IPPROTO_IPV6,
+                libc::IPV6_V6ONLY,
+                &value as *const _ as *const libc::c_void,
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    /// Retrieves the value set for `IPV6_V6ONLY` on this socket.
+    #[cfg(unix)]
+    pub fn only_v6(&self) -> io::Result<bool> {
+        let mut value: libc::c_int = 0;
+        let mut len = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+        let ret = unsafe {
+            libc::getsockopt(
+                self.as_raw_fd(),
+                libc::IPPROTO_IPV6,
+                libc::IPV6_V6ONLY,
+                &mut value as *mut _ as *mut libc::c_void,
+                &mut len,
+            )
+        };
+        if ret == 0 {
+            Ok(value != 0)
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    /// Sets the value of the `IP_TRANSPARENT` option on this socket.
+    ///
+    /// This allows the socket to accept connections and send packets with a
+    /// non-local source or destination address, which is required by
+    /// transparent proxies built on Linux's TPROXY iptables target. Binding
+    /// also typically requires the `CAP_NET_ADMIN` capability.
+    #[cfg(target_os = "linux")]
+    pub fn set_ip_transparent(&self, transparent: bool) -> io::Result<()> {
+        let value: libc::c_int = transparent as libc::c_int;
+        let ret = unsafe {
+            libc::setsockopt(
+                self.as_raw_fd(),
+                libc::SOL_IP,
+                libc::IP_TRANSPARENT,
+                &value as *const _ as *const libc::c_void,
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    /// Retrieves the value set for `IP_TRANSPARENT` on this socket.
+    #[cfg(target_os = "linux")]
+    pub fn ip_transparent(&self) -> io::Result<bool> {
+        let mut value: libc::c_int = 0;
+        let mut len = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+        let ret = unsafe {
+            libc::getsockopt(
+                self.as_raw_fd(),
+                libc::SOL_IP,
+                libc::IP_TRANSPARENT,
+                &mut value as *mut _ as *mut libc::c_void,
+                &mut len,
+            )
+        };
+        if ret == 0 {
+            Ok(value != 0)
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
     /// Sets the size of the TCP send buffer on this socket.
     ///
     /// On most operating systems, this sets the `SO_SNDBUF` socket option.
@@ -348,6 +576,68 @@ impl TcpSocket {
         self.inner.get_recv_buffer_size()
     }
 
+    /// Sets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// If set, this disables the Nagle algorithm. This means that segments
+    /// are always sent as soon as possible, even if there is only a small
+    /// amount of data. When not set, data is buffered until there is a
+    /// sufficient amount to send out, thereby avoiding the frequent sending
+    /// of small packets.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::TcpSocket;
+    ///
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let socket = TcpSocket::new_v4()?;
+    ///     socket.set_nodelay(true)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(unix)]
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        let value: libc::c_int = nodelay as libc::c_int;
+        let ret = unsafe {
+            libc::setsockopt(
+                self.as_raw_fd(),
+                libc::IPPROTO_TCP,
+                libc::TCP_NODELAY,
+                &value as *const _ as *const libc::c_void,
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    /// Retrieves the value set for `TCP_NODELAY` on this socket.
+    #[cfg(unix)]
+    pub fn nodelay(&self) -> io::Result<bool> {
+        let mut value: libc::c_int = 0;
+        let mut len = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+        let ret = unsafe {
+            libc::getsockopt(
+                self.as_raw_fd(),
+                libc::IPPROTO_TCP,
+                libc::TCP_NODELAY,
+                &mut value as *mut _ as *mut libc::c_void,
+                &mut len,
+            )
+        };
+        if ret == 0 {
+            Ok(value != 0)
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
     /// Get the local address of this socket.
     ///
     /// Will fail on windows if called before `bind`.
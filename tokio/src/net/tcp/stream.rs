@@ -85,6 +85,19 @@ impl TcpStream {
     /// [`ToSocketAddrs`]: trait@crate::net::ToSocketAddrs
     /// [`TcpSocket`]: struct@crate::net::TcpSocket
     ///
+    /// Addresses are tried strictly in the order `addr` yields them, one at a
+    /// time; this does not implement the Happy Eyeballs algorithm (RFC 8305)
+    /// of racing an IPv6 and IPv4 address in parallel with a short stagger.
+    /// On a dual-stack host whose `AAAA` record leads to an address that's
+    /// routable but silently dropped rather than rejected, this can stall
+    /// for a full connect timeout before falling through to the working
+    /// `A` record. If that's a problem, resolve the addresses yourself,
+    /// split them by family, and race [`TcpSocket::connect`] futures (e.g.
+    /// with [`tokio::select!`]) instead of calling this function directly.
+    ///
+    /// [`TcpSocket::connect`]: crate::net::TcpSocket::connect
+    /// [`tokio::select!`]: crate::select
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -128,6 +141,41 @@ impl TcpStream {
         }))
     }
 
+    /// Establishes a connection to the specified `addr`, failing with
+    /// [`ErrorKind::TimedOut`](std::io::ErrorKind::TimedOut) if the
+    /// connection isn't established within `timeout`.
+    ///
+    /// This is equivalent to calling [`connect`](TcpStream::connect) inside
+    /// [`tokio::time::timeout`](crate::time::timeout), except that the
+    /// timeout error is converted into an [`io::Error`] so callers that
+    /// only handle I/O errors don't need a second error type.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// # async fn dox() -> Result<(), Box<dyn std::error::Error>> {
+    /// let stream = TcpStream::connect_timeout("127.0.0.1:8080", Duration::from_secs(5)).await?;
+    /// # drop(stream);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "time")]
+    pub async fn connect_timeout<A: ToSocketAddrs>(
+        addr: A,
+        timeout: std::time::Duration,
+    ) -> io::Result<TcpStream> {
+        match crate::time::timeout(timeout, TcpStream::connect(addr)).await {
+            Ok(result) => result,
+            Err(_elapsed) => Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "connect timed out",
+            )),
+        }
+    }
+
     /// Establishes a connection to the specified `addr`.
     async fn connect_addr(addr: SocketAddr) -> io::Result<TcpStream> {
         let sys = mio::net::TcpStream::connect(addr)?;
@@ -1179,6 +1227,68 @@ impl TcpStream {
         self.io.set_ttl(ttl)
     }
 
+    /// Gets the value of the `SO_OOBINLINE` option on this socket.
+    ///
+    /// For more information about this option, see [`set_oobinline`].
+    ///
+    /// [`set_oobinline`]: TcpStream::set_oobinline
+    #[cfg(unix)]
+    pub fn oobinline(&self) -> io::Result<bool> {
+        sys::oobinline(self)
+    }
+
+    /// Sets the value of the `SO_OOBINLINE` option on this socket.
+    ///
+    /// If set, out-of-band data received on the socket is placed directly
+    /// into the normal data input queue, in the byte order it was sent as
+    /// urgent data, instead of being delivered separately and requiring the
+    /// `MSG_OOB` flag to read it. Most applications that want to observe TCP
+    /// urgent bytes without a separate out-of-band read path want this on.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::TcpStream;
+    ///
+    /// # async fn dox() -> Result<(), Box<dyn std::error::Error>> {
+    /// let stream = TcpStream::connect("127.0.0.1:8080").await?;
+    ///
+    /// stream.set_oobinline(true)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    pub fn set_oobinline(&self, oobinline: bool) -> io::Result<()> {
+        sys::set_oobinline(self, oobinline)
+    }
+
+    /// Sends a single byte of urgent (out-of-band) data on this socket.
+    ///
+    /// This corresponds to a `send` call with the `MSG_OOB` flag set, which
+    /// causes the TCP urgent pointer to be advanced to the given byte. Unless
+    /// [`set_oobinline`](TcpStream::set_oobinline) is enabled on the peer, the
+    /// byte is delivered to the peer out-of-band and must be read separately
+    /// from the normal stream.
+    #[cfg(unix)]
+    pub async fn send_oob(&self, byte: u8) -> io::Result<()> {
+        self.io
+            .registration()
+            .async_io(Interest::WRITABLE, || sys::send_oob(self, byte))
+            .await
+    }
+
+    /// Retrieves the original destination address of a connection redirected
+    /// to this socket by a Linux TPROXY or REDIRECT iptables rule, via the
+    /// `SO_ORIGINAL_DST` socket option.
+    ///
+    /// This is how a transparent proxy recovers the address the client
+    /// actually intended to connect to, since the TCP connection itself now
+    /// terminates locally.
+    #[cfg(target_os = "linux")]
+    pub fn original_dst(&self) -> io::Result<SocketAddr> {
+        sys::original_dst(self)
+    }
+
     // These lifetime markers also appear in the generated documentation, and make
     // it more clear that this is a *borrowed* split.
     #[allow(clippy::needless_lifetimes)]
@@ -1202,8 +1312,13 @@ impl TcpStream {
     /// **Note:** Dropping the write half will shut down the write half of the TCP
     /// stream. This is equivalent to calling [`shutdown()`] on the `TcpStream`.
     ///
+    /// The two halves can be rejoined into a single `TcpStream` with
+    /// [`OwnedReadHalf::reunite`], as long as they originated from the same
+    /// `into_split` call.
+    ///
     /// [`split`]: TcpStream::split()
     /// [`shutdown()`]: fn@crate::io::AsyncWriteExt::shutdown
+    /// [`OwnedReadHalf::reunite`]: crate::net::tcp::OwnedReadHalf::reunite
     pub fn into_split(self) -> (OwnedReadHalf, OwnedWriteHalf) {
         split_owned(self)
     }
@@ -1306,6 +1421,8 @@ impl fmt::Debug for TcpStream {
 #[cfg(unix)]
 mod sys {
     use super::TcpStream;
+    use std::io;
+    use std::mem::size_of;
     use std::os::unix::prelude::*;
 
     impl AsRawFd for TcpStream {
@@ -1313,6 +1430,81 @@ mod sys {
             self.io.as_raw_fd()
         }
     }
+
+    pub(super) fn oobinline(stream: &TcpStream) -> io::Result<bool> {
+        unsafe {
+            let mut value: libc::c_int = 0;
+            let mut len = size_of::<libc::c_int>() as libc::socklen_t;
+            let ret = libc::getsockopt(
+                stream.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_OOBINLINE,
+                &mut value as *mut _ as *mut libc::c_void,
+                &mut len,
+            );
+            if ret == 0 {
+                Ok(value != 0)
+            } else {
+                Err(io::Error::last_os_error())
+            }
+        }
+    }
+
+    pub(super) fn set_oobinline(stream: &TcpStream, oobinline: bool) -> io::Result<()> {
+        unsafe {
+            let value: libc::c_int = oobinline as libc::c_int;
+            let ret = libc::setsockopt(
+                stream.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_OOBINLINE,
+                &value as *const _ as *const libc::c_void,
+                size_of::<libc::c_int>() as libc::socklen_t,
+            );
+            if ret == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            }
+        }
+    }
+
+    pub(super) fn send_oob(stream: &TcpStream, byte: u8) -> io::Result<()> {
+        unsafe {
+            let buf = [byte];
+            let ret = libc::send(
+                stream.as_raw_fd(),
+                buf.as_ptr() as *const libc::c_void,
+                1,
+                libc::MSG_OOB,
+            );
+            if ret < 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(super) fn original_dst(stream: &TcpStream) -> io::Result<std::net::SocketAddr> {
+        unsafe {
+            let mut value: libc::sockaddr_in = std::mem::zeroed();
+            let mut len = size_of::<libc::sockaddr_in>() as libc::socklen_t;
+            let ret = libc::getsockopt(
+                stream.as_raw_fd(),
+                libc::SOL_IP,
+                libc::SO_ORIGINAL_DST,
+                &mut value as *mut _ as *mut libc::c_void,
+                &mut len,
+            );
+            if ret != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let ip = std::net::Ipv4Addr::from(u32::from_be(value.sin_addr.s_addr));
+            let port = u16::from_be(value.sin_port);
+            Ok(std::net::SocketAddr::from((ip, port)))
+        }
+    }
 }
 
 #[cfg(windows)]
@@ -522,6 +522,11 @@ impl UdpSocket {
     /// When the socket buffer is full, `Err(io::ErrorKind::WouldBlock)` is
     /// returned. This function is usually paired with `writable()`.
     ///
+    /// If the CPU cost of a per-datagram syscall shows up in profiles —
+    /// sending many small datagrams in a burst, for example — see
+    /// [`send_many`](UdpSocket::send_many), which batches multiple sends
+    /// into as few syscalls as possible (`sendmmsg` on Linux).
+    ///
     /// # Returns
     ///
     /// If successful, `Ok(n)` is returned, where `n` is the number of bytes
@@ -956,6 +961,10 @@ impl UdpSocket {
     /// `Waker` from the `Context` passed to the most recent call will be scheduled to
     /// receive a wakeup.
     ///
+    /// This is the building block `Sink` adapters for framed datagram codecs
+    /// are expected to call from their `poll_ready`/`start_send`
+    /// implementations, since it never allocates a future per packet.
+    ///
     /// # Return value
     ///
     /// The function returns:
@@ -1083,6 +1092,10 @@ impl UdpSocket {
     /// `Waker` from the `Context` passed to the most recent call will be scheduled to
     /// receive a wakeup.
     ///
+    /// This is the building block `Stream` adapters for framed datagram
+    /// codecs are expected to call from their `poll_next` implementations,
+    /// since it never allocates a future per packet.
+    ///
     /// # Return value
     ///
     /// The function returns:
@@ -1126,6 +1139,18 @@ impl UdpSocket {
     /// When there is no pending data, `Err(io::ErrorKind::WouldBlock)` is
     /// returned. This function is usually paired with `readable()`.
     ///
+    /// Because a single readiness event can mean several datagrams are
+    /// already queued on the socket, callers that want to drain all of them
+    /// (for example a QUIC implementation processing a burst of packets)
+    /// should keep calling `try_recv_from` after each success, in a loop,
+    /// until it returns `WouldBlock`, rather than going back to `readable()`
+    /// after every datagram. If per-datagram syscall overhead is the
+    /// bottleneck rather than readiness polling, [`recv_many`] does the same
+    /// drain with `recvmmsg` on Linux instead of one `recv_from` per
+    /// datagram.
+    ///
+    /// [`recv_many`]: UdpSocket::recv_many
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1474,6 +1499,91 @@ impl UdpSocket {
     pub fn take_error(&self) -> io::Result<Option<io::Error>> {
         self.io.take_error()
     }
+
+    /// Sends multiple datagrams in as few syscalls as possible.
+    ///
+    /// On Linux this is backed by `sendmmsg`, so a batch of `msgs` can go out
+    /// in a single syscall instead of one per datagram. On platforms without
+    /// `sendmmsg` this falls back to issuing `send_to` for each message in
+    /// turn, so the method is portable even though only Linux gets the
+    /// syscall-count win.
+    ///
+    /// Returns the number of messages, counted from the front of `msgs`,
+    /// that were sent. An error on the first message is returned as `Err`,
+    /// so the count is at least `1` when `msgs` is non-empty. An error after
+    /// that ends the batch early and is not returned: a count `n` short of
+    /// `msgs.len()` means sending stopped at `msgs[n]`; retry starting from
+    /// `msgs[n..]`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::{SendMsg, UdpSocket};
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:0").await?;
+    ///     let dst = "127.0.0.1:8081".parse().unwrap();
+    ///     let msgs = [SendMsg { buf: b"hello", target: dst }, SendMsg { buf: b"world", target: dst }];
+    ///     let n = socket.send_many(&msgs).await?;
+    ///     println!("sent {} of {} messages", n, msgs.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_many(&self, msgs: &[SendMsg<'_>]) -> io::Result<usize> {
+        if msgs.is_empty() {
+            return Ok(0);
+        }
+
+        mmsg::send_many(self, msgs).await
+    }
+
+    /// Receives multiple datagrams in as few syscalls as possible.
+    ///
+    /// On Linux this is backed by `recvmmsg`, filling every buffer in `bufs`
+    /// from a single syscall when that many datagrams are already queued. On
+    /// platforms without `recvmmsg` this falls back to issuing `recv_from`
+    /// once per buffer.
+    ///
+    /// Returns one `(len, addr)` pair per datagram actually received, in the
+    /// same order as `bufs`. The returned `Vec` may be shorter than `bufs`
+    /// if fewer datagrams were available.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::net::UdpSocket;
+    /// use std::io;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> io::Result<()> {
+    ///     let socket = UdpSocket::bind("127.0.0.1:8080").await?;
+    ///     let mut a = [0u8; 1024];
+    ///     let mut b = [0u8; 1024];
+    ///     let received = socket.recv_many(&mut [&mut a, &mut b]).await?;
+    ///     for (len, addr) in received {
+    ///         println!("received {} bytes from {}", len, addr);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn recv_many(&self, bufs: &mut [&mut [u8]]) -> io::Result<Vec<(usize, SocketAddr)>> {
+        if bufs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        mmsg::recv_many(self, bufs).await
+    }
+}
+
+/// A single outgoing datagram for [`UdpSocket::send_many`].
+#[derive(Debug, Clone, Copy)]
+pub struct SendMsg<'a> {
+    /// The payload to send.
+    pub buf: &'a [u8],
+    /// The destination address for this datagram.
+    pub target: SocketAddr,
 }
 
 impl TryFrom<std::net::UdpSocket> for UdpSocket {
@@ -1517,3 +1627,232 @@ mod sys {
         }
     }
 }
+
+/// Batched send/recv backing `UdpSocket::send_many`/`recv_many`.
+///
+/// Linux gets a real `sendmmsg`/`recvmmsg` fast path; every other platform
+/// falls back to one syscall per message so the public API stays portable.
+mod mmsg {
+    use super::{SendMsg, UdpSocket};
+    use std::io;
+    use std::net::SocketAddr;
+
+    #[cfg(target_os = "linux")]
+    pub(super) async fn send_many(
+        socket: &UdpSocket,
+        msgs: &[SendMsg<'_>],
+    ) -> io::Result<usize> {
+        socket
+            .io
+            .registration()
+            .async_io(super::Interest::WRITABLE, || linux::sendmmsg(socket, msgs))
+            .await
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(super) async fn send_many(
+        socket: &UdpSocket,
+        msgs: &[SendMsg<'_>],
+    ) -> io::Result<usize> {
+        let mut sent = 0;
+        for msg in msgs {
+            match socket.send_to(msg.buf, msg.target).await {
+                Ok(_) => sent += 1,
+                Err(_) if sent > 0 => return Ok(sent),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(sent)
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(super) async fn recv_many(
+        socket: &UdpSocket,
+        bufs: &mut [&mut [u8]],
+    ) -> io::Result<Vec<(usize, SocketAddr)>> {
+        socket
+            .io
+            .registration()
+            .async_io(super::Interest::READABLE, || linux::recvmmsg(socket, bufs))
+            .await
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(super) async fn recv_many(
+        socket: &UdpSocket,
+        bufs: &mut [&mut [u8]],
+    ) -> io::Result<Vec<(usize, SocketAddr)>> {
+        let mut received = Vec::with_capacity(bufs.len());
+        for buf in bufs {
+            // Wait only for the first datagram; after that, take just what
+            // is already queued.
+            let result = if received.is_empty() {
+                socket.recv_from(buf).await
+            } else {
+                match socket.try_recv_from(buf) {
+                    Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                    result => result,
+                }
+            };
+            match result {
+                Ok(result) => received.push(result),
+                Err(_) if !received.is_empty() => return Ok(received),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(received)
+    }
+
+    #[cfg(target_os = "linux")]
+    mod linux {
+        use super::{SendMsg, UdpSocket};
+        use std::io;
+        use std::mem::{size_of, zeroed};
+        use std::net::{IpAddr, SocketAddr};
+        use std::os::unix::io::AsRawFd;
+
+        fn socket_addr_to_raw(addr: SocketAddr) -> (libc::sockaddr_storage, libc::socklen_t) {
+            let mut storage: libc::sockaddr_storage = unsafe { zeroed() };
+            let len = match addr {
+                SocketAddr::V4(v4) => {
+                    let raw = libc::sockaddr_in {
+                        sin_family: libc::AF_INET as libc::sa_family_t,
+                        sin_port: v4.port().to_be(),
+                        sin_addr: libc::in_addr {
+                            s_addr: u32::from_ne_bytes(v4.ip().octets()),
+                        },
+                        sin_zero: [0; 8],
+                    };
+                    unsafe {
+                        std::ptr::write(&mut storage as *mut _ as *mut libc::sockaddr_in, raw);
+                    }
+                    size_of::<libc::sockaddr_in>()
+                }
+                SocketAddr::V6(v6) => {
+                    let raw = libc::sockaddr_in6 {
+                        sin6_family: libc::AF_INET6 as libc::sa_family_t,
+                        sin6_port: v6.port().to_be(),
+                        sin6_flowinfo: v6.flowinfo(),
+                        sin6_addr: libc::in6_addr {
+                            s6_addr: v6.ip().octets(),
+                        },
+                        sin6_scope_id: v6.scope_id(),
+                    };
+                    unsafe {
+                        std::ptr::write(&mut storage as *mut _ as *mut libc::sockaddr_in6, raw);
+                    }
+                    size_of::<libc::sockaddr_in6>()
+                }
+            };
+            (storage, len as libc::socklen_t)
+        }
+
+        fn raw_to_socket_addr(storage: &libc::sockaddr_storage, len: libc::socklen_t) -> io::Result<SocketAddr> {
+            match storage.ss_family as i32 {
+                libc::AF_INET if len as usize >= size_of::<libc::sockaddr_in>() => {
+                    let raw = unsafe { &*(storage as *const _ as *const libc::sockaddr_in) };
+                    let ip = IpAddr::from(raw.sin_addr.s_addr.to_ne_bytes());
+                    Ok(SocketAddr::new(ip, u16::from_be(raw.sin_port)))
+                }
+                libc::AF_INET6 if len as usize >= size_of::<libc::sockaddr_in6>() => {
+                    let raw = unsafe { &*(storage as *const _ as *const libc::sockaddr_in6) };
+                    let ip = IpAddr::from(raw.sin6_addr.s6_addr);
+                    Ok(SocketAddr::new(ip, u16::from_be(raw.sin6_port)))
+                }
+                _ => Err(io::Error::new(io::ErrorKind::InvalidData, "unsupported address family")),
+            }
+        }
+
+        pub(super) fn sendmmsg(socket: &UdpSocket, msgs: &[SendMsg<'_>]) -> io::Result<usize> {
+            let mut storages: Vec<(libc::sockaddr_storage, libc::socklen_t)> =
+                msgs.iter().map(|m| socket_addr_to_raw(m.target)).collect();
+            let mut iovecs: Vec<libc::iovec> = msgs
+                .iter()
+                .map(|m| libc::iovec {
+                    iov_base: m.buf.as_ptr() as *mut libc::c_void,
+                    iov_len: m.buf.len(),
+                })
+                .collect();
+            let mut headers: Vec<libc::mmsghdr> = (0..msgs.len())
+                .map(|i| libc::mmsghdr {
+                    msg_hdr: libc::msghdr {
+                        msg_name: &mut storages[i].0 as *mut _ as *mut libc::c_void,
+                        msg_namelen: storages[i].1,
+                        msg_iov: &mut iovecs[i],
+                        msg_iovlen: 1,
+                        msg_control: std::ptr::null_mut(),
+                        msg_controllen: 0,
+                        msg_flags: 0,
+                    },
+                    msg_len: 0,
+                })
+                .collect();
+
+            let ret = unsafe {
+                libc::sendmmsg(
+                    socket.as_raw_fd(),
+                    headers.as_mut_ptr(),
+                    headers.len() as libc::c_uint,
+                    0,
+                )
+            };
+
+            if ret < 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(ret as usize)
+            }
+        }
+
+        pub(super) fn recvmmsg(
+            socket: &UdpSocket,
+            bufs: &mut [&mut [u8]],
+        ) -> io::Result<Vec<(usize, SocketAddr)>> {
+            let mut storages: Vec<libc::sockaddr_storage> =
+                (0..bufs.len()).map(|_| unsafe { zeroed() }).collect();
+            let mut iovecs: Vec<libc::iovec> = bufs
+                .iter_mut()
+                .map(|b| libc::iovec {
+                    iov_base: b.as_mut_ptr() as *mut libc::c_void,
+                    iov_len: b.len(),
+                })
+                .collect();
+            let mut headers: Vec<libc::mmsghdr> = (0..bufs.len())
+                .map(|i| libc::mmsghdr {
+                    msg_hdr: libc::msghdr {
+                        msg_name: &mut storages[i] as *mut _ as *mut libc::c_void,
+                        msg_namelen: size_of::<libc::sockaddr_storage>() as libc::socklen_t,
+                        msg_iov: &mut iovecs[i],
+                        msg_iovlen: 1,
+                        msg_control: std::ptr::null_mut(),
+                        msg_controllen: 0,
+                        msg_flags: 0,
+                    },
+                    msg_len: 0,
+                })
+                .collect();
+
+            let ret = unsafe {
+                libc::recvmmsg(
+                    socket.as_raw_fd(),
+                    headers.as_mut_ptr(),
+                    headers.len() as libc::c_uint,
+                    0,
+                    std::ptr::null_mut(),
+                )
+            };
+
+            if ret < 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let mut out = Vec::with_capacity(ret as usize);
+            for i in 0..ret as usize {
+                let len = headers[i].msg_len as usize;
+                let addr = raw_to_socket_addr(&storages[i], headers[i].msg_hdr.msg_namelen)?;
+                out.push((len, addr));
+            }
+            Ok(out)
+        }
+    }
+}
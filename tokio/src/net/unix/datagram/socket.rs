@@ -318,6 +318,18 @@ impl UnixDatagram {
         UnixDatagram::new(socket)
     }
 
+    /// Creates a new `UnixDatagram` bound to the given address, which may be
+    /// an abstract-namespace address created with
+    /// [`SocketAddr::from_abstract_name`].
+    ///
+    /// [`SocketAddr::from_abstract_name`]: std::os::linux::net::SocketAddrExt::from_abstract_name
+    #[cfg(target_os = "linux")]
+    pub fn bind_addr(addr: &net::SocketAddr) -> io::Result<UnixDatagram> {
+        let socket = net::UnixDatagram::bind_addr(addr)?;
+        socket.set_nonblocking(true)?;
+        UnixDatagram::from_std(socket)
+    }
+
     /// Creates an unnamed pair of connected sockets.
     ///
     /// This function will create a pair of interconnected Unix sockets for
@@ -509,6 +521,19 @@ impl UnixDatagram {
         self.io.connect(path)
     }
 
+    /// Connects the socket to the given address, which may be an
+    /// abstract-namespace address created with
+    /// [`SocketAddr::from_abstract_name`].
+    ///
+    /// [`SocketAddr::from_abstract_name`]: std::os::linux::net::SocketAddrExt::from_abstract_name
+    #[cfg(target_os = "linux")]
+    pub fn connect_addr(&self, addr: &net::SocketAddr) -> io::Result<()> {
+        let socket = std::mem::ManuallyDrop::new(unsafe {
+            net::UnixDatagram::from_raw_fd(self.as_raw_fd())
+        });
+        socket.connect_addr(addr)
+    }
+
     /// Sends data on the socket to the socket's peer.
     ///
     /// # Cancel safety
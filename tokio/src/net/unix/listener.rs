@@ -52,6 +52,9 @@ cfg_net_unix! {
 impl UnixListener {
     /// Creates a new `UnixListener` bound to the specified path.
     ///
+    /// To bind to a Linux abstract-namespace address instead of a filesystem
+    /// path, use [`bind_addr`](UnixListener::bind_addr).
+    ///
     /// # Panics
     ///
     /// This function panics if thread-local runtime is not set.
@@ -88,6 +91,33 @@ impl UnixListener {
         Ok(UnixListener { io })
     }
 
+    /// Creates a new `UnixListener` bound to the given address, which may be
+    /// an abstract-namespace address created with
+    /// [`SocketAddr::from_abstract_name`].
+    ///
+    /// Abstract-namespace sockets are a Linux extension with no filesystem
+    /// path; they disappear on their own once every handle to them is
+    /// closed, rather than needing to be `unlink`ed.
+    ///
+    /// [`SocketAddr::from_abstract_name`]: std::os::linux::net::SocketAddrExt::from_abstract_name
+    ///
+    /// # Panics
+    ///
+    /// This function panics if thread-local runtime is not set.
+    ///
+    /// The runtime is usually set implicitly when this function is called
+    /// from a future driven by a tokio runtime, otherwise runtime can be set
+    /// explicitly with [`Runtime::enter`](crate::runtime::Runtime::enter) function.
+    ///
+    /// `std::os::unix::net::UnixListener::bind_addr` has been stable since
+    /// Rust 1.70, within this crate's MSRV (see `.clippy.toml`).
+    #[cfg(target_os = "linux")]
+    pub fn bind_addr(addr: &net::SocketAddr) -> io::Result<UnixListener> {
+        let listener = net::UnixListener::bind_addr(addr)?;
+        listener.set_nonblocking(true)?;
+        UnixListener::from_std(listener)
+    }
+
     /// Turn a [`tokio::net::UnixListener`] into a [`std::os::unix::net::UnixListener`].
     ///
     /// The returned [`std::os::unix::net::UnixListener`] will have nonblocking mode
@@ -1,4 +1,12 @@
 //! Unix domain socket utility types
+//!
+//! This module is only available on Unix, since it builds directly on
+//! `std::os::unix::net`. Windows 10 added its own `AF_UNIX` support, but
+//! `std` doesn't expose it (there is no `std::os::windows::net` equivalent),
+//! so bringing it to Tokio would mean hand-rolling the socket creation,
+//! `SOCKADDR_UN` handling, and overlapped I/O plumbing directly against
+//! winsock. That's out of scope for now; track upstream `std` support for
+//! Windows Unix sockets before revisiting this.
 
 // This module does not currently provide any public API, but it was
 // unintentionally defined as a public module. Hide it from the documentation
@@ -22,3 +30,5 @@ pub(crate) use stream::UnixStream;
 
 mod ucred;
 pub use ucred::UCred;
+
+pub mod pipe;
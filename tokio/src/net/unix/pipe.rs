@@ -0,0 +1,213 @@
+//! Asynchronous Unix pipes.
+//!
+//! Unlike a socket, a pipe is unidirectional: a [`Sender`] can only write
+//! and a [`Receiver`] can only read. Use [`pipe()`] for an anonymous pipe
+//! connecting two ends of this process (or shared with a child process),
+//! or [`OpenOptions`] to open one half of a named pipe (FIFO) created with
+//! `mkfifo(1)` ahead of time.
+
+use crate::io::unix::AsyncFd;
+use crate::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Options for opening a FIFO (named pipe) half.
+///
+/// Unlike [`std::fs::OpenOptions`], the file this produces is always opened
+/// non-blocking, since it's going to be driven by the Tokio reactor.
+#[derive(Clone, Debug, Default)]
+pub struct OpenOptions {
+    read_write: bool,
+}
+
+impl OpenOptions {
+    /// Creates a blank set of options ready for configuration.
+    pub fn new() -> OpenOptions {
+        OpenOptions::default()
+    }
+
+    /// Opens the FIFO in read-write mode, rather than strictly read-only or
+    /// write-only.
+    ///
+    /// Both halves are opened with `O_NONBLOCK`, so a FIFO opened
+    /// write-only fails with `ENXIO` while no reader has it open, and a
+    /// [`Receiver`] opened read-only reads end-of-file while no writer is
+    /// connected. Opening it read-write makes the handle count as both a
+    /// reader and a writer, which avoids both without requiring a dummy
+    /// peer to be kept open.
+    pub fn read_write(&mut self, value: bool) -> &mut Self {
+        self.read_write = value;
+        self
+    }
+
+    /// Opens the FIFO at `path` for reading.
+    pub fn open_receiver<P: AsRef<Path>>(&self, path: P) -> io::Result<Receiver> {
+        let file = self.open(path, false)?;
+        Ok(Receiver {
+            io: AsyncFd::new(file)?,
+        })
+    }
+
+    /// Opens the FIFO at `path` for writing.
+    pub fn open_sender<P: AsRef<Path>>(&self, path: P) -> io::Result<Sender> {
+        let file = self.open(path, true)?;
+        Ok(Sender {
+            io: AsyncFd::new(file)?,
+        })
+    }
+
+    fn open<P: AsRef<Path>>(&self, path: P, write: bool) -> io::Result<File> {
+        std::fs::OpenOptions::new()
+            .read(!write || self.read_write)
+            .write(write || self.read_write)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(path)
+    }
+}
+
+/// Creates a new anonymous pipe, returning the writable and readable ends.
+///
+/// This is built on the `pipe(2)` syscall; both ends are closed automatically
+/// when dropped. It's most commonly used to capture or feed the stdio of a
+/// spawned [`Command`](crate::process::Command).
+pub fn pipe() -> io::Result<(Sender, Receiver)> {
+    let mut fds = [0 as RawFd; 2];
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let (read_fd, write_fd) = (fds[0], fds[1]);
+
+    if let Err(e) = set_nonblocking_cloexec(read_fd).and_then(|_| set_nonblocking_cloexec(write_fd))
+    {
+        unsafe {
+            libc::close(read_fd);
+            libc::close(write_fd);
+        }
+        return Err(e);
+    }
+
+    let receiver = Receiver {
+        io: AsyncFd::new(unsafe { File::from_raw_fd(read_fd) })?,
+    };
+    let sender = Sender {
+        io: AsyncFd::new(unsafe { File::from_raw_fd(write_fd) })?,
+    };
+    Ok((sender, receiver))
+}
+
+fn set_nonblocking_cloexec(fd: RawFd) -> io::Result<()> {
+    unsafe {
+        let flags = libc::fcntl(fd, libc::F_GETFL);
+        if flags < 0 || libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let flags = libc::fcntl(fd, libc::F_GETFD);
+        if flags < 0 || libc::fcntl(fd, libc::F_SETFD, flags | libc::FD_CLOEXEC) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// The readable half of a Unix pipe, created by [`pipe()`] or
+/// [`OpenOptions::open_receiver`].
+#[derive(Debug)]
+pub struct Receiver {
+    io: AsyncFd<File>,
+}
+
+/// The writable half of a Unix pipe, created by [`pipe()`] or
+/// [`OpenOptions::open_sender`].
+#[derive(Debug)]
+pub struct Sender {
+    io: AsyncFd<File>,
+}
+
+impl AsyncRead for Receiver {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        loop {
+            let mut guard = match self.io.poll_read_ready(cx) {
+                Poll::Ready(guard) => guard?,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let unfilled = buf.initialize_unfilled();
+            match guard.try_io(|inner| inner.get_ref().read(unfilled)) {
+                Ok(Ok(n)) => {
+                    // SAFETY: `n` bytes were just written into `unfilled` by `read`.
+                    unsafe {
+                        buf.assume_init(n);
+                    }
+                    buf.advance(n);
+                    return Poll::Ready(Ok(()));
+                }
+                Ok(Err(e)) => return Poll::Ready(Err(e)),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+impl AsyncWrite for Sender {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            let mut guard = match self.io.poll_write_ready(cx) {
+                Poll::Ready(guard) => guard?,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match guard.try_io(|inner| inner.get_ref().write(buf)) {
+                Ok(result) => return Poll::Ready(result),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsRawFd for Receiver {
+    fn as_raw_fd(&self) -> RawFd {
+        self.io.as_raw_fd()
+    }
+}
+
+impl AsRawFd for Sender {
+    fn as_raw_fd(&self) -> RawFd {
+        self.io.as_raw_fd()
+    }
+}
+
+impl IntoRawFd for Receiver {
+    fn into_raw_fd(self) -> RawFd {
+        let file = self.io.into_inner();
+        file.into_raw_fd()
+    }
+}
+
+impl IntoRawFd for Sender {
+    fn into_raw_fd(self) -> RawFd {
+        let file = self.io.into_inner();
+        file.into_raw_fd()
+    }
+}
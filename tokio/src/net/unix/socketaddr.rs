@@ -22,6 +22,13 @@ impl SocketAddr {
     pub fn as_pathname(&self) -> Option<&Path> {
         self.0.as_pathname()
     }
+
+    /// Returns the contents of this address if it is an abstract-namespace
+    /// address, without the leading NUL byte.
+    #[cfg(target_os = "linux")]
+    pub fn as_abstract_name(&self) -> Option<&[u8]> {
+        self.0.as_abstract_namespace()
+    }
 }
 
 impl fmt::Debug for SocketAddr {
@@ -59,6 +59,27 @@ impl UnixStream {
         Ok(stream)
     }
 
+    /// Connects to the socket at the given address, which may be an
+    /// abstract-namespace address created with
+    /// [`SocketAddr::from_abstract_name`].
+    ///
+    /// [`SocketAddr::from_abstract_name`]: std::os::linux::net::SocketAddrExt::from_abstract_name
+    #[cfg(target_os = "linux")]
+    pub async fn connect_addr(addr: &std::os::unix::net::SocketAddr) -> io::Result<UnixStream> {
+        let stream = std::os::unix::net::UnixStream::connect_addr(addr)?;
+        stream.set_nonblocking(true)?;
+        let stream = mio::net::UnixStream::from_std(stream);
+        let stream = UnixStream::new(stream)?;
+
+        poll_fn(|cx| stream.io.registration().poll_write_ready(cx)).await?;
+
+        if let Some(e) = stream.io.take_error()? {
+            return Err(e);
+        }
+
+        Ok(stream)
+    }
+
     /// Wait for any of the requested ready states.
     ///
     /// This function is usually paired with `try_read()` or `try_write()`. It
@@ -680,6 +701,17 @@ impl UnixStream {
     /// [`readable()`]: UnixStream::readable()
     /// [`writable()`]: UnixStream::writable()
     /// [`ready()`]: UnixStream::ready()
+    ///
+    /// This is also the extension point for ancillary data that this type
+    /// doesn't expose directly, such as passing open file descriptors between
+    /// processes with `SCM_RIGHTS`: call `libc::sendmsg`/`libc::recvmsg`
+    /// against [`AsRawFd::as_raw_fd`] from inside the closure, building the
+    /// `msghdr`/`cmsghdr` ancillary buffer yourself (or with a crate like
+    /// [`sendfd`]), and let `try_io` turn the readiness wait and
+    /// `WouldBlock` retry into the usual non-blocking dance.
+    ///
+    /// [`AsRawFd::as_raw_fd`]: std::os::unix::io::AsRawFd::as_raw_fd
+    /// [`sendfd`]: https://docs.rs/sendfd
     pub fn try_io<R>(
         &self,
         interest: Interest,
@@ -824,8 +856,13 @@ impl UnixStream {
     /// **Note:** Dropping the write half will shut down the write half of the
     /// stream. This is equivalent to calling [`shutdown()`] on the `UnixStream`.
     ///
+    /// The two halves can be rejoined into a single `UnixStream` with
+    /// [`OwnedReadHalf::reunite`], as long as they originated from the same
+    /// `into_split` call.
+    ///
     /// [`split`]: Self::split()
     /// [`shutdown()`]: fn@crate::io::AsyncWriteExt::shutdown
+    /// [`OwnedReadHalf::reunite`]: crate::net::unix::OwnedReadHalf::reunite
     pub fn into_split(self) -> (OwnedReadHalf, OwnedWriteHalf) {
         split_owned(self)
     }
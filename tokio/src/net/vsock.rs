@@ -0,0 +1,421 @@
+//! VSOCK (`AF_VSOCK`) types, for communication between a virtual machine and
+//! its host.
+//!
+//! VSOCK is only available on Linux, and only where the `vsock` kernel
+//! module is loaded (this is the default inside most VMs created by
+//! Firecracker, cloud-hypervisor, and QEMU with a `vhost-vsock` device).
+
+use crate::io::unix::AsyncFd;
+use crate::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use std::fmt;
+use std::io;
+use std::mem;
+use std::os::unix::io::{AsRawFd, IntoRawFd, RawFd};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// An address for a VSOCK socket, identifying a context ID (`cid`) and a
+/// port.
+///
+/// The `cid` identifies a VM (or the host, via [`VMADDR_CID_HOST`]); the
+/// port behaves like a TCP port number scoped to that `cid`.
+///
+/// [`VMADDR_CID_HOST`]: VsockAddr::host_cid
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct VsockAddr {
+    cid: u32,
+    port: u32,
+}
+
+impl VsockAddr {
+    /// Creates a new `VsockAddr` from a context ID and a port.
+    pub fn new(cid: u32, port: u32) -> VsockAddr {
+        VsockAddr { cid, port }
+    }
+
+    /// The context ID that identifies the hypervisor host.
+    pub fn host_cid() -> u32 {
+        libc::VMADDR_CID_HOST
+    }
+
+    /// The special context ID meaning "any context".
+    pub fn any_cid() -> u32 {
+        libc::VMADDR_CID_ANY
+    }
+
+    /// Returns the context ID of this address.
+    pub fn cid(&self) -> u32 {
+        self.cid
+    }
+
+    /// Returns the port of this address.
+    pub fn port(&self) -> u32 {
+        self.port
+    }
+
+    fn to_raw(&self) -> libc::sockaddr_vm {
+        let mut raw: libc::sockaddr_vm = unsafe { mem::zeroed() };
+        raw.svm_family = libc::AF_VSOCK as libc::sa_family_t;
+        raw.svm_cid = self.cid;
+        raw.svm_port = self.port;
+        raw
+    }
+
+    fn from_raw(raw: &libc::sockaddr_vm) -> VsockAddr {
+        VsockAddr {
+            cid: raw.svm_cid,
+            port: raw.svm_port,
+        }
+    }
+}
+
+impl fmt::Debug for VsockAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VsockAddr")
+            .field("cid", &self.cid)
+            .field("port", &self.port)
+            .finish()
+    }
+}
+
+impl fmt::Display for VsockAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cid:{} port:{}", self.cid, self.port)
+    }
+}
+
+/// An owned file descriptor that closes itself on drop; the inner type
+/// handed to [`AsyncFd`].
+struct RawSocket(RawFd);
+
+impl AsRawFd for RawSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+impl Drop for RawSocket {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.0);
+        }
+    }
+}
+
+
+fn set_nonblocking_cloexec(fd: RawFd) -> io::Result<()> {
+    unsafe {
+        let flags = libc::fcntl(fd, libc::F_GETFL);
+        if flags < 0 || libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let flags = libc::fcntl(fd, libc::F_GETFD);
+        if flags < 0 || libc::fcntl(fd, libc::F_SETFD, flags | libc::FD_CLOEXEC) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+fn new_vsock_socket() -> io::Result<RawFd> {
+    let fd = unsafe { libc::socket(libc::AF_VSOCK, libc::SOCK_STREAM, 0) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if let Err(e) = set_nonblocking_cloexec(fd) {
+        unsafe {
+            libc::close(fd);
+        }
+        return Err(e);
+    }
+    Ok(fd)
+}
+
+fn getsockname(fd: RawFd) -> io::Result<VsockAddr> {
+    unsafe {
+        let mut raw: libc::sockaddr_vm = mem::zeroed();
+        let mut len = mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t;
+        let ret = libc::getsockname(fd, &mut raw as *mut _ as *mut libc::sockaddr, &mut len);
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(VsockAddr::from_raw(&raw))
+    }
+}
+
+fn getpeername(fd: RawFd) -> io::Result<VsockAddr> {
+    unsafe {
+        let mut raw: libc::sockaddr_vm = mem::zeroed();
+        let mut len = mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t;
+        let ret = libc::getpeername(fd, &mut raw as *mut _ as *mut libc::sockaddr, &mut len);
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(VsockAddr::from_raw(&raw))
+    }
+}
+
+/// A VSOCK socket server, listening for connections from a VM (or the host).
+///
+/// Create a listener with [`VsockListener::bind`], then accept connections
+/// with [`VsockListener::accept`].
+pub struct VsockListener {
+    fd: AsyncFd<RawSocket>,
+}
+
+impl VsockListener {
+    /// Binds a new `VsockListener` to the given address.
+    pub fn bind(addr: VsockAddr) -> io::Result<VsockListener> {
+        let fd = new_vsock_socket()?;
+        let raw = addr.to_raw();
+        let ret = unsafe {
+            libc::bind(
+                fd,
+                &raw as *const _ as *const libc::sockaddr,
+                mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t,
+            )
+        };
+        if ret != 0 {
+            let err = io::Error::last_os_error();
+            unsafe {
+                libc::close(fd);
+            }
+            return Err(err);
+        }
+        if unsafe { libc::listen(fd, 1024) } != 0 {
+            let err = io::Error::last_os_error();
+            unsafe {
+                libc::close(fd);
+            }
+            return Err(err);
+        }
+
+        Ok(VsockListener {
+            fd: AsyncFd::new(RawSocket(fd))?,
+        })
+    }
+
+    /// Returns the local address this listener is bound to.
+    pub fn local_addr(&self) -> io::Result<VsockAddr> {
+        getsockname(self.fd.as_raw_fd())
+    }
+
+    /// Accepts a new incoming connection.
+    pub async fn accept(&self) -> io::Result<(VsockStream, VsockAddr)> {
+        loop {
+            let mut guard = self.fd.readable().await?;
+
+            match guard.try_io(|inner| accept_raw(inner.as_raw_fd())) {
+                Ok(result) => return result,
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+fn accept_raw(fd: RawFd) -> io::Result<(VsockStream, VsockAddr)> {
+    unsafe {
+        let mut raw: libc::sockaddr_vm = mem::zeroed();
+        let mut len = mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t;
+        let client = libc::accept(fd, &mut raw as *mut _ as *mut libc::sockaddr, &mut len);
+        if client < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        set_nonblocking_cloexec(client)?;
+        let addr = VsockAddr::from_raw(&raw);
+        Ok((
+            VsockStream {
+                fd: AsyncFd::new(RawSocket(client))?,
+            },
+            addr,
+        ))
+    }
+}
+
+impl fmt::Debug for VsockListener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VsockListener")
+            .field("fd", &self.fd.as_raw_fd())
+            .finish()
+    }
+}
+
+impl AsRawFd for VsockListener {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+/// A VSOCK connection between a VM and its host.
+pub struct VsockStream {
+    fd: AsyncFd<RawSocket>,
+}
+
+impl VsockStream {
+    /// Connects to the given VSOCK address.
+    pub async fn connect(addr: VsockAddr) -> io::Result<VsockStream> {
+        let raw_fd = new_vsock_socket()?;
+        let raw = addr.to_raw();
+        let ret = unsafe {
+            libc::connect(
+                raw_fd,
+                &raw as *const _ as *const libc::sockaddr,
+                mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t,
+            )
+        };
+
+        if ret != 0 {
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::WouldBlock && err.raw_os_error() != Some(libc::EINPROGRESS) {
+                unsafe {
+                    libc::close(raw_fd);
+                }
+                return Err(err);
+            }
+        }
+
+        let fd = AsyncFd::new(RawSocket(raw_fd))?;
+
+        loop {
+            let mut guard = fd.writable().await?;
+            let result = guard.try_io(|inner| {
+                let mut err: libc::c_int = 0;
+                let mut len = mem::size_of::<libc::c_int>() as libc::socklen_t;
+                let ret = unsafe {
+                    libc::getsockopt(
+                        inner.as_raw_fd(),
+                        libc::SOL_SOCKET,
+                        libc::SO_ERROR,
+                        &mut err as *mut _ as *mut libc::c_void,
+                        &mut len,
+                    )
+                };
+                if ret != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                if err == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::from_raw_os_error(err))
+                }
+            });
+
+            match result {
+                Ok(Ok(())) => return Ok(VsockStream { fd }),
+                Ok(Err(e)) => return Err(e),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    /// Returns the local address of this stream.
+    pub fn local_addr(&self) -> io::Result<VsockAddr> {
+        getsockname(self.fd.as_raw_fd())
+    }
+
+    /// Returns the address of the remote end of this stream.
+    pub fn peer_addr(&self) -> io::Result<VsockAddr> {
+        getpeername(self.fd.as_raw_fd())
+    }
+}
+
+impl AsyncRead for VsockStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        loop {
+            let mut guard = match self.fd.poll_read_ready(cx) {
+                Poll::Ready(guard) => guard?,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let unfilled = buf.initialize_unfilled();
+            match guard.try_io(|inner| {
+                let fd = inner.get_ref().as_raw_fd();
+                let ret = unsafe {
+                    libc::read(fd, unfilled.as_mut_ptr() as *mut libc::c_void, unfilled.len())
+                };
+                if ret < 0 {
+                    Err(io::Error::last_os_error())
+                } else {
+                    Ok(ret as usize)
+                }
+            }) {
+                Ok(Ok(n)) => {
+                    buf.advance(n);
+                    return Poll::Ready(Ok(()));
+                }
+                Ok(Err(e)) => return Poll::Ready(Err(e)),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+impl AsyncWrite for VsockStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            let mut guard = match self.fd.poll_write_ready(cx) {
+                Poll::Ready(guard) => guard?,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match guard.try_io(|inner| {
+                let fd = inner.get_ref().as_raw_fd();
+                let ret =
+                    unsafe { libc::write(fd, buf.as_ptr() as *const libc::c_void, buf.len()) };
+                if ret < 0 {
+                    Err(io::Error::last_os_error())
+                } else {
+                    Ok(ret as usize)
+                }
+            }) {
+                Ok(result) => return Poll::Ready(result),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let fd = self.fd.as_raw_fd();
+        if unsafe { libc::shutdown(fd, libc::SHUT_WR) } != 0 {
+            return Poll::Ready(Err(io::Error::last_os_error()));
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl fmt::Debug for VsockStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VsockStream")
+            .field("fd", &self.fd.as_raw_fd())
+            .finish()
+    }
+}
+
+impl AsRawFd for VsockStream {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+
+impl IntoRawFd for VsockStream {
+    fn into_raw_fd(self) -> RawFd {
+        let fd = self.fd.as_raw_fd();
+        let inner = self.fd.into_inner();
+        // The fd is handed to the caller; don't let `RawSocket::drop` close it.
+        mem::forget(inner);
+        fd
+    }
+}
@@ -221,6 +221,8 @@ use std::task::Poll;
 pub struct Command {
     std: StdCommand,
     kill_on_drop: bool,
+    #[cfg(unix)]
+    kill_process_group: bool,
 }
 
 pub(crate) struct SpawnedChild {
@@ -542,6 +544,8 @@ impl Command {
     /// If stronger guarantees are required, it is recommended to avoid dropping
     /// a [`Child`] handle where possible, and instead utilize `child.wait().await`
     /// or `child.kill().await` where possible.
+    ///
+    /// [`Child`]: Child
     pub fn kill_on_drop(&mut self, kill_on_drop: bool) -> &mut Command {
         self.kill_on_drop = kill_on_drop;
         self
@@ -549,9 +553,18 @@ impl Command {
 
     /// Sets the [process creation flags][1] to be passed to `CreateProcess`.
     ///
-    /// These will always be ORed with `CREATE_UNICODE_ENVIRONMENT`.
+    /// These will always be ORed with `CREATE_UNICODE_ENVIRONMENT`. Common
+    /// flags include `CREATE_NO_WINDOW`, to spawn the child without a
+    /// console window, and `CREATE_NEW_PROCESS_GROUP`, so the child doesn't
+    /// receive this process's `CTRL_C`/`CTRL_BREAK` events.
+    ///
+    /// This crate does not wrap Windows job objects: attaching the child to
+    /// one for e.g. kill-on-close semantics across a whole descendant tree
+    /// means calling out to the `windows-sys`/`win32job` APIs yourself,
+    /// using [`Child::raw_handle`] to get a handle to assign.
     ///
     /// [1]: https://msdn.microsoft.com/en-us/library/windows/desktop/ms684863(v=vs.85).aspx
+    /// [`Child::raw_handle`]: Child::raw_handle
     #[cfg(windows)]
     #[cfg_attr(docsrs, doc(cfg(windows)))]
     pub fn creation_flags(&mut self, flags: u32) -> &mut Command {
@@ -559,6 +572,19 @@ impl Command {
         self
     }
 
+    /// Appends a literal argument to the command line, passed to
+    /// `CreateProcess` without any quoting or escaping performed by Tokio.
+    ///
+    /// This is an escape hatch for programs like `cmd.exe` whose own
+    /// argument parsing doesn't follow the quoting conventions that
+    /// [`arg`](Command::arg) assumes. Most programs should prefer `arg`.
+    #[cfg(windows)]
+    #[cfg_attr(docsrs, doc(cfg(windows)))]
+    pub fn raw_arg<S: AsRef<OsStr>>(&mut self, text_to_append_as_is: S) -> &mut Command {
+        self.std.raw_arg(text_to_append_as_is);
+        self
+    }
+
     /// Sets the child process's user ID. This translates to a
     /// `setuid` call in the child process. Failure in the `setuid`
     /// call will cause the spawn to fail.
@@ -631,6 +657,127 @@ impl Command {
         self
     }
 
+    /// Sets the process group ID (PGID) of the child process, equivalent to
+    /// a `setpgid` call in the child after `fork` (but before `exec`).
+    ///
+    /// Process groups determine which processes receive a particular
+    /// terminal-originated signal, such as `SIGINT` from Ctrl+C; placing a
+    /// subprocess in its own group is a common way to stop a signal sent to
+    /// the parent from also reaching it.
+    ///
+    /// Setting this to `0` will create a new process group, with the
+    /// process group ID being equal to the process ID of the child process.
+    ///
+    /// This is gated behind `process_group` instead of being merged into
+    /// `pre_exec` because the standard library implements it directly via
+    /// `posix_spawn` where available, which is more efficient than running a
+    /// closure post-fork.
+    ///
+    /// `CommandExt::process_group` has been stable since Rust 1.64, within
+    /// this crate's MSRV (see `.clippy.toml`).
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn process_group(&mut self, pgroup: i32) -> &mut Command {
+        self.std.process_group(pgroup);
+        self
+    }
+
+    /// Places the child in a new process group, and arranges for
+    /// [`kill_on_drop`] (and [`Child::kill`]/[`Child::start_kill`]) to
+    /// signal that whole group instead of just the direct child.
+    ///
+    /// Shell wrappers and other programs that spawn grandchildren of their
+    /// own leave those grandchildren behind when only the direct child is
+    /// killed; since the new group's ID is the child's own PID, signaling
+    /// `-pid` reaches everything still running in it. This is equivalent to
+    /// `process_group(0)` plus switching the kill target, so it cannot be
+    /// combined with an explicit [`process_group`] call.
+    ///
+    /// [`kill_on_drop`]: Command::kill_on_drop
+    /// [`process_group`]: Command::process_group
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn kill_on_drop_process_group(&mut self, kill_on_drop: bool) -> &mut Command {
+        self.std.process_group(0);
+        self.kill_on_drop = kill_on_drop;
+        self.kill_process_group = true;
+        self
+    }
+
+    /// Sets a resource limit (`RLIMIT_*`) for the child process, applied via
+    /// `setrlimit` in the pre-exec phase, after `fork` but before `exec`.
+    ///
+    /// `resource` is one of the `libc::RLIMIT_*` constants (for example,
+    /// `libc::RLIMIT_NOFILE`); `soft` and `hard` become the new soft and
+    /// hard limits for that resource. Calling this more than once adds an
+    /// additional limit for each `resource` rather than replacing earlier
+    /// calls.
+    ///
+    /// Unlike an arbitrary [`pre_exec`] closure, this only ever performs the
+    /// single `setrlimit` call needed, which is safe to run in the child
+    /// after `fork` from a multithreaded process.
+    ///
+    /// [`pre_exec`]: Command::pre_exec
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn rlimit(&mut self, resource: libc::c_int, soft: u64, hard: u64) -> &mut Command {
+        let limit = libc::rlimit {
+            rlim_cur: soft as libc::rlim_t,
+            rlim_max: hard as libc::rlim_t,
+        };
+
+        // Safety: `setrlimit` is async-signal-safe, so it's fine to call
+        // from the child between `fork` and `exec`.
+        unsafe {
+            self.std.pre_exec(move || {
+                if libc::setrlimit(resource as _, &limit) == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            });
+        }
+        self
+    }
+
+    /// Changes the root directory of the child process to `root`, applied
+    /// via `chroot` in the pre-exec phase, after `fork` but before `exec`.
+    ///
+    /// Only the child's filesystem root is affected; the parent process is
+    /// untouched. Relative paths (including the executable path itself and
+    /// any [`current_dir`]) are still resolved against the working
+    /// directory at the time of `exec`, so pair this with [`current_dir`]
+    /// when the child also needs to start inside the new root.
+    ///
+    /// Unlike an arbitrary [`pre_exec`] closure, this only ever performs the
+    /// single `chroot` call needed, which is safe to run in the child after
+    /// `fork` from a multithreaded process.
+    ///
+    /// [`pre_exec`]: Command::pre_exec
+    /// [`current_dir`]: Command::current_dir
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    pub fn chroot<P: AsRef<Path>>(&mut self, root: P) -> &mut Command {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let root =
+            CString::new(root.as_ref().as_os_str().as_bytes()).expect("path contained a nul byte");
+
+        // Safety: `chroot` is async-signal-safe, so it's fine to call from
+        // the child between `fork` and `exec`.
+        unsafe {
+            self.std.pre_exec(move || {
+                if libc::chroot(root.as_ptr()) == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            });
+        }
+        self
+    }
+
     /// Executes the command as a child process, returning a handle to it.
     ///
     /// By default, stdin, stdout and stderr are inherited from the parent.
@@ -699,7 +846,12 @@ impl Command {
     /// if the system process limit is reached (which includes other applications
     /// running on the system).
     pub fn spawn(&mut self) -> io::Result<Child> {
-        imp::spawn_child(&mut self.std).map(|spawned_child| Child {
+        #[cfg(unix)]
+        let kill_process_group = self.kill_process_group;
+        #[cfg(not(unix))]
+        let kill_process_group = false;
+
+        imp::spawn_child(&mut self.std, kill_process_group).map(|spawned_child| Child {
             child: FusedChild::Child(ChildDropGuard {
                 inner: spawned_child.child,
                 kill_on_drop: self.kill_on_drop,
@@ -826,6 +978,8 @@ impl From<StdCommand> for Command {
         Command {
             std,
             kill_on_drop: false,
+            #[cfg(unix)]
+            kill_process_group: false,
         }
     }
 }
@@ -964,6 +1118,43 @@ impl Child {
         }
     }
 
+    /// Opens a `pidfd` (see `pidfd_open(2)`) referring to the child process
+    /// while it is still running. Returns `None` if the child has exited.
+    ///
+    /// A pidfd identifies the process by file descriptor rather than by PID,
+    /// so unlike the PID from [`id`](Child::id) it cannot be reused by an
+    /// unrelated process once the child exits, which makes it suitable for
+    /// handing to an external tool (e.g. a cgroup/sandboxing supervisor, or
+    /// `poll`/`epoll`-based monitoring) that needs to watch this specific
+    /// child without racing a PID reuse. Tokio's own [`wait`](Child::wait)
+    /// does not use this fd; it keeps using `waitid`/`SIGCHLD`-based
+    /// reaping, since that works uniformly across all Unix platforms Tokio
+    /// supports and not just Linux.
+    ///
+    /// Unlike the free function [`wait_for_exit`](crate::process::wait_for_exit), this method does not
+    /// race a PID reuse between reading the PID and opening the pidfd: this
+    /// `Child` is the only thing in the process that is allowed to reap its
+    /// own PID (see the "Reaping strategy" note on the `process` module's
+    /// Unix implementation), and `self.id()` already returns `None` once
+    /// that reap has happened, so the PID read by `pidfd_open` here is
+    /// guaranteed to still refer to this child, not a PID that has already
+    /// been recycled by some unrelated reaper.
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+    pub fn pidfd(&self) -> Option<io::Result<std::fs::File>> {
+        use std::os::unix::io::FromRawFd;
+
+        let pid = self.id()?;
+        let ret = unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) };
+        Some(if ret >= 0 {
+            // Safety: `ret` is a valid, open file descriptor returned by
+            // `pidfd_open` that we now own exclusively.
+            Ok(unsafe { std::fs::File::from_raw_fd(ret as std::os::unix::io::RawFd) })
+        } else {
+            Err(io::Error::last_os_error())
+        })
+    }
+
     /// Attempts to force the child to exit, but does not wait for the request
     /// to take effect.
     ///
@@ -1012,6 +1203,43 @@ impl Child {
         Ok(())
     }
 
+    /// Sends a graceful termination request and waits up to `grace` for the
+    /// child to exit on its own before escalating to [`kill`](Child::kill).
+    ///
+    /// On Unix this sends `SIGTERM`, which a process can catch to clean up
+    /// before exiting, unlike `SIGKILL` (what [`kill`](Child::kill) sends).
+    /// If `grace` elapses before the child exits, this falls back to
+    /// [`kill`](Child::kill) to finish the job.
+    ///
+    /// Windows has no equivalent of `SIGTERM` for an arbitrary child
+    /// process: `GenerateConsoleCtrlEvent` only reaches processes sharing
+    /// the parent's console, which most spawned children won't, and relies
+    /// on the child cooperatively handling the event. So on Windows this
+    /// always escalates straight to [`kill`](Child::kill) (`TerminateProcess`)
+    /// without waiting out `grace`.
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub async fn terminate_timeout(
+        &mut self,
+        grace: std::time::Duration,
+    ) -> io::Result<ExitStatus> {
+        #[cfg(unix)]
+        if let Some(pid) = self.id() {
+            // Safety: `pid` identifies a child process we still hold a
+            // handle to, so it's safe to signal.
+            unsafe {
+                libc::kill(pid as libc::pid_t, libc::SIGTERM);
+            }
+
+            if let Ok(status) = crate::time::timeout(grace, self.wait()).await {
+                return status;
+            }
+        }
+
+        self.start_kill()?;
+        self.wait().await
+    }
+
     /// Waits for the child to exit completely, returning the status that it
     /// exited with. This function will continue to have the same return value
     /// after it has been called at least once.
@@ -1054,6 +1282,12 @@ impl Child {
     ///     let _ = child.wait().await;
     /// }
     /// ```
+    ///
+    /// Unlike `stdin`, `wait` does not take or close `stdout`/`stderr`, so a
+    /// task can stream a child's output while concurrently awaiting its
+    /// exit status, e.g. by taking `child.stdout` and reading it in a
+    /// `tokio::select!` alongside `child.wait()`, or via a separate spawned
+    /// task.
     pub async fn wait(&mut self) -> io::Result<ExitStatus> {
         // Ensure stdin is closed so the child isn't stuck waiting on
         // input while the parent is waiting for it to exit.
@@ -1145,6 +1379,33 @@ impl Child {
     }
 }
 
+/// Waits for an arbitrary process to exit, without requiring it to be a
+/// child of the current process.
+///
+/// This is useful for supervisors that adopt pre-existing daemons (for
+/// example, ones discovered by scanning `/proc`) and need to notice when
+/// they go away, without resorting to polling. Since the kernel's
+/// `wait`-family syscalls only ever report on a caller's own children,
+/// there's no exit status available here the way there is for a [`Child`]
+/// — only the fact that `pid` is no longer running.
+///
+/// Note that `pid` is reused by the OS once the process has been reaped by
+/// whoever is its parent, so on a long enough timescale it's possible for
+/// this to resolve because of an unrelated process that was later assigned
+/// the same `pid`, rather than the one originally intended. Callers that
+/// need to rule this out should re-check their own source of truth (e.g.
+/// that the daemon's pidfile still names `pid`) once this future resolves.
+///
+/// # Platform support
+///
+/// This is implemented with `pidfd_open(2)`, which is Linux/Android-only;
+/// the function doesn't exist on other platforms.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[cfg_attr(docsrs, doc(cfg(any(target_os = "linux", target_os = "android"))))]
+pub async fn wait_for_exit(pid: u32) -> io::Result<()> {
+    imp::pidfd::wait_for_exit(pid).await
+}
+
 /// The standard input stream for spawned children.
 ///
 /// This type implements the `AsyncWrite` trait to pass data to the stdin handle of
@@ -1238,7 +1499,8 @@ impl TryInto<Stdio> for ChildStderr {
 
 #[cfg(unix)]
 mod sys {
-    use std::os::unix::io::{AsRawFd, RawFd};
+    use std::io;
+    use std::os::unix::io::{AsRawFd, OwnedFd, RawFd};
 
     use super::{ChildStderr, ChildStdin, ChildStdout};
 
@@ -1259,11 +1521,48 @@ mod sys {
             self.inner.as_raw_fd()
         }
     }
+
+    impl ChildStdin {
+        /// Converts this handle into an owned file descriptor, e.g. to pass
+        /// it on to another process or wrap it in a custom type.
+        ///
+        /// Like [`TryInto<Stdio>`](TryInto), this puts the underlying fd
+        /// back into blocking mode before handing it over, since the
+        /// receiver has no way of knowing it was ever non-blocking.
+        pub fn into_owned_fd(self) -> io::Result<OwnedFd> {
+            super::imp::convert_to_owned_fd(self.inner)
+        }
+    }
+
+    impl ChildStdout {
+        /// Converts this handle into an owned file descriptor, e.g. to pass
+        /// it on to another process or wrap it in a custom type.
+        ///
+        /// Like [`TryInto<Stdio>`](TryInto), this puts the underlying fd
+        /// back into blocking mode before handing it over, since the
+        /// receiver has no way of knowing it was ever non-blocking.
+        pub fn into_owned_fd(self) -> io::Result<OwnedFd> {
+            super::imp::convert_to_owned_fd(self.inner)
+        }
+    }
+
+    impl ChildStderr {
+        /// Converts this handle into an owned file descriptor, e.g. to pass
+        /// it on to another process or wrap it in a custom type.
+        ///
+        /// Like [`TryInto<Stdio>`](TryInto), this puts the underlying fd
+        /// back into blocking mode before handing it over, since the
+        /// receiver has no way of knowing it was ever non-blocking.
+        pub fn into_owned_fd(self) -> io::Result<OwnedFd> {
+            super::imp::convert_to_owned_fd(self.inner)
+        }
+    }
 }
 
 #[cfg(windows)]
 mod sys {
-    use std::os::windows::io::{AsRawHandle, RawHandle};
+    use std::io;
+    use std::os::windows::io::{AsRawHandle, OwnedHandle, RawHandle};
 
     use super::{ChildStderr, ChildStdin, ChildStdout};
 
@@ -1284,6 +1583,30 @@ mod sys {
             self.inner.as_raw_handle()
         }
     }
+
+    impl ChildStdin {
+        /// Converts this handle into an owned handle, e.g. to pass it on to
+        /// another process or wrap it in a custom type.
+        pub fn into_owned_handle(self) -> io::Result<OwnedHandle> {
+            super::imp::convert_to_owned_handle(self.inner)
+        }
+    }
+
+    impl ChildStdout {
+        /// Converts this handle into an owned handle, e.g. to pass it on to
+        /// another process or wrap it in a custom type.
+        pub fn into_owned_handle(self) -> io::Result<OwnedHandle> {
+            super::imp::convert_to_owned_handle(self.inner)
+        }
+    }
+
+    impl ChildStderr {
+        /// Converts this handle into an owned handle, e.g. to pass it on to
+        /// another process or wrap it in a custom type.
+        pub fn into_owned_handle(self) -> io::Result<OwnedHandle> {
+            super::imp::convert_to_owned_handle(self.inner)
+        }
+    }
 }
 
 #[cfg(all(test, not(loom)))]
@@ -20,6 +20,34 @@
 //! Note that this means that this isn't really scalable, but then again
 //! processes in general aren't scalable (e.g. millions) so it shouldn't be that
 //! bad in theory...
+//!
+//! ## Reaping strategy
+//!
+//! The [`SIGCHLD`] handler and the queue of orphaned children (children whose
+//! `Child` future was dropped before they exited) are process-global state,
+//! shared by every [`Command`](crate::process::Command) spawned from any
+//! runtime in the process — there's no per-runtime scoping, because
+//! `SIGCHLD` is itself delivered at the process level, not the runtime
+//! level. This is harmless when multiple runtimes exist in one process: they
+//! all share the same `SIGCHLD` listener and orphan queue, so each runtime's
+//! children are still reaped correctly regardless of which runtime's signal
+//! driver happens to observe the signal first.
+//!
+//! This also means there's no way to opt a particular runtime, or the whole
+//! process, out of this machinery: registering the `SIGCHLD` handler is
+//! unconditional as soon as any `Command` is spawned. This is intentional,
+//! since without it dropped `Child`s would never be reaped and would leak as
+//! zombie processes. A host application that installs its own `SIGCHLD`
+//! handler doesn't need to worry about a conflict here: handler registration
+//! goes through `signal-hook-registry`, which chains to any handler that was
+//! already registered for the same signal rather than replacing it. The one
+//! thing that *does* conflict is a host calling `waitpid`/`wait` itself on a
+//! PID that was spawned through `tokio::process::Command`: only one caller
+//! can ever successfully collect a given child's exit status, so a host that
+//! needs to reap a child itself should spawn it with `std::process::Command`
+//! instead of `tokio::process::Command`.
+//!
+//! [`SIGCHLD`]: crate::signal::unix::SignalKind::child
 
 pub(crate) mod driver;
 
@@ -42,7 +70,7 @@ use std::fmt;
 use std::fs::File;
 use std::future::Future;
 use std::io;
-use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 use std::pin::Pin;
 use std::process::{Child as StdChild, ExitStatus, Stdio};
 use std::task::Context;
@@ -89,6 +117,7 @@ impl OrphanQueue<StdChild> for GlobalOrphanQueue {
 #[must_use = "futures do nothing unless polled"]
 pub(crate) struct Child {
     inner: Reaper<StdChild, GlobalOrphanQueue, Signal>,
+    kill_process_group: bool,
 }
 
 impl fmt::Debug for Child {
@@ -99,7 +128,10 @@ impl fmt::Debug for Child {
     }
 }
 
-pub(crate) fn spawn_child(cmd: &mut std::process::Command) -> io::Result<SpawnedChild> {
+pub(crate) fn spawn_child(
+    cmd: &mut std::process::Command,
+    kill_process_group: bool,
+) -> io::Result<SpawnedChild> {
     let mut child = cmd.spawn()?;
     let stdin = stdio(child.stdin.take())?;
     let stdout = stdio(child.stdout.take())?;
@@ -110,6 +142,7 @@ pub(crate) fn spawn_child(cmd: &mut std::process::Command) -> io::Result<Spawned
     Ok(SpawnedChild {
         child: Child {
             inner: Reaper::new(child, GlobalOrphanQueue, signal),
+            kill_process_group,
         },
         stdin,
         stdout,
@@ -129,6 +162,18 @@ impl Child {
 
 impl Kill for Child {
     fn kill(&mut self) -> io::Result<()> {
+        if self.kill_process_group {
+            // The child was placed in its own process group (group ID ==
+            // its own PID), so signaling `-pid` reaches every process
+            // still running in that group, not just the direct child.
+            let pid = self.inner.id() as libc::pid_t;
+            return if unsafe { libc::kill(-pid, libc::SIGKILL) } == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::last_os_error())
+            };
+        }
+
         self.inner.kill()
     }
 }
@@ -189,6 +234,19 @@ pub(crate) fn convert_to_stdio(io: PollEvented<Pipe>) -> io::Result<Stdio> {
     Ok(Stdio::from(fd))
 }
 
+// `OwnedFd` has been stable since Rust 1.63, within this crate's MSRV
+// (see `.clippy.toml`).
+pub(crate) fn convert_to_owned_fd(io: PollEvented<Pipe>) -> io::Result<OwnedFd> {
+    let mut fd = io.into_inner()?.fd;
+
+    // Same rationale as `convert_to_stdio`: hand back a fd in blocking mode,
+    // since the caller is handing it off to something outside of Tokio that
+    // won't know to flip it back.
+    set_nonblocking(&mut fd, false)?;
+
+    Ok(OwnedFd::from(fd))
+}
+
 impl Source for Pipe {
     fn register(
         &mut self,
@@ -255,3 +313,67 @@ where
 
     Ok(Some(PollEvented::new(pipe)?))
 }
+
+// `pidfd_open(2)` lets us hold a pollable handle on an arbitrary process,
+// not just a direct child, which is what makes `wait_for_exit` possible.
+// It's Linux/Android-only, so there's no equivalent implementation for
+// other Unix platforms here.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) mod pidfd {
+    use super::{set_nonblocking, Pipe};
+    use crate::io::PollEvented;
+    use std::future::Future;
+    use std::io;
+    use std::os::unix::io::{FromRawFd, OwnedFd};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    fn pidfd_open(pid: libc::pid_t) -> io::Result<OwnedFd> {
+        // Safety: `pidfd_open` takes a pid and a flags argument (only `0`
+        // is currently defined), and returns either a newly opened fd or
+        // `-1` on error, per its man page contract.
+        let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // Safety: `pidfd_open` just handed back a fresh, valid, and
+        // not-yet-owned file descriptor.
+        Ok(unsafe { OwnedFd::from_raw_fd(fd as _) })
+    }
+
+    /// A future that resolves once the process behind a `pidfd` exits.
+    ///
+    /// A pidfd becomes readable exactly once, when its process exits, and
+    /// stays readable forever after, so there's nothing to actually read:
+    /// observing readiness is the whole signal.
+    pub(crate) struct WaitForExit {
+        pidfd: PollEvented<Pipe>,
+    }
+
+    impl WaitForExit {
+        pub(crate) fn new(pid: libc::pid_t) -> io::Result<Self> {
+            let fd = pidfd_open(pid)?;
+            let mut pipe = Pipe::from(fd);
+            set_nonblocking(&mut pipe, true)?;
+            Ok(Self {
+                pidfd: PollEvented::new(pipe)?,
+            })
+        }
+    }
+
+    impl Future for WaitForExit {
+        type Output = io::Result<()>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            match self.pidfd.registration().poll_read_ready(cx) {
+                Poll::Ready(Ok(_)) => Poll::Ready(Ok(())),
+                Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+
+    pub(crate) async fn wait_for_exit(pid: u32) -> io::Result<()> {
+        WaitForExit::new(pid as libc::pid_t)?.await
+    }
+}
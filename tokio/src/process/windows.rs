@@ -65,7 +65,12 @@ struct Waiting {
 unsafe impl Sync for Waiting {}
 unsafe impl Send for Waiting {}
 
-pub(crate) fn spawn_child(cmd: &mut StdCommand) -> io::Result<SpawnedChild> {
+pub(crate) fn spawn_child(cmd: &mut StdCommand, _kill_process_group: bool) -> io::Result<SpawnedChild> {
+    // Unlike process groups on Unix, tearing down a whole descendant tree on
+    // Windows needs a job object, which has no equivalent in `std::process`
+    // and isn't wrapped here; see `Command::creation_flags` for pointers to
+    // doing that yourself. `kill_on_drop_process_group` is Unix-only, so
+    // this parameter is always `false` here.
     let mut child = cmd.spawn()?;
     let stdin = stdio(child.stdin.take());
     let stdout = stdio(child.stdout.take());
@@ -209,3 +214,35 @@ pub(crate) fn convert_to_stdio(io: PollEvented<NamedPipe>) -> io::Result<Stdio>
         Ok(Stdio::from_raw_handle(dup_handle))
     }
 }
+
+pub(crate) fn convert_to_owned_handle(
+    io: PollEvented<NamedPipe>,
+) -> io::Result<std::os::windows::io::OwnedHandle> {
+    let named_pipe = io.into_inner()?;
+
+    // Same duplication dance as `convert_to_stdio`: mio's `NamedPipe` doesn't
+    // implement `IntoRawHandle`, so the only way to hand the handle to the
+    // caller is to duplicate it before the original is closed by drop.
+    unsafe {
+        let mut dup_handle = INVALID_HANDLE_VALUE;
+        let cur_proc = GetCurrentProcess();
+
+        let status = DuplicateHandle(
+            cur_proc,
+            named_pipe.as_raw_handle(),
+            cur_proc,
+            &mut dup_handle,
+            0 as DWORD,
+            FALSE,
+            DUPLICATE_SAME_ACCESS,
+        );
+
+        if status == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(std::os::windows::io::OwnedHandle::from_raw_handle(
+            dup_handle,
+        ))
+    }
+}
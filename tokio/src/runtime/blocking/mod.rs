@@ -5,6 +5,8 @@
 
 mod pool;
 pub(crate) use pool::{spawn_blocking, BlockingPool, Spawner};
+#[cfg(tokio_unstable)]
+pub use pool::BlockingPoolMetrics;
 
 mod schedule;
 mod shutdown;
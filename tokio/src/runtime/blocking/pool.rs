@@ -48,6 +48,10 @@ struct Inner {
 
     // Customizable wait timeout
     keep_alive: Duration,
+
+    // Cap on the number of queued tasks before `spawn` starts rejecting new
+    // ones. `None` means the queue is unbounded.
+    queue_depth_cap: Option<usize>,
 }
 
 struct Shared {
@@ -69,12 +73,58 @@ struct Shared {
     /// This is a counter used to iterate worker_threads in a consistent order (for loom's
     /// benefit)
     worker_thread_index: usize,
+    /// Number of tasks that have been popped off the queue to be run. A task
+    /// is counted when a thread takes it, before it runs; tasks dropped on
+    /// shutdown are not counted.
+    tasks_executed: u64,
 }
 
 type Task = task::UnownedTask<NoopSchedule>;
 
 const KEEP_ALIVE: Duration = Duration::from_secs(10);
 
+/// A point-in-time snapshot of the blocking pool's state.
+///
+/// Obtained via [`Handle::blocking_pool_metrics`].
+///
+/// [`Handle::blocking_pool_metrics`]: crate::runtime::Handle::blocking_pool_metrics
+#[cfg(tokio_unstable)]
+#[derive(Debug, Clone, Copy)]
+pub struct BlockingPoolMetrics {
+    num_threads: usize,
+    num_idle_threads: usize,
+    queue_depth: usize,
+    num_tasks_executed: u64,
+}
+
+#[cfg(tokio_unstable)]
+impl BlockingPoolMetrics {
+    /// Returns the number of blocking threads currently alive, including
+    /// idle ones.
+    pub fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    /// Returns the number of blocking threads currently idle, waiting for
+    /// work.
+    pub fn num_idle_threads(&self) -> usize {
+        self.num_idle_threads
+    }
+
+    /// Returns the number of blocking tasks currently queued, waiting for a
+    /// free thread.
+    pub fn queue_depth(&self) -> usize {
+        self.queue_depth
+    }
+
+    /// Returns the total number of blocking tasks that have been executed
+    /// since the runtime started. A task is counted as soon as a blocking
+    /// thread takes it off the queue, before it runs.
+    pub fn num_tasks_executed(&self) -> u64 {
+        self.num_tasks_executed
+    }
+}
+
 /// Run the provided function on an executor dedicated to blocking operations.
 pub(crate) fn spawn_blocking<F, R>(func: F) -> JoinHandle<R>
 where
@@ -105,6 +155,7 @@ impl BlockingPool {
                         last_exiting_thread: None,
                         worker_threads: HashMap::new(),
                         worker_thread_index: 0,
+                        tasks_executed: 0,
                     }),
                     condvar: Condvar::new(),
                     thread_name: builder.thread_name.clone(),
@@ -113,6 +164,7 @@ impl BlockingPool {
                     before_stop: builder.before_stop.clone(),
                     thread_cap,
                     keep_alive,
+                    queue_depth_cap: builder.max_blocking_queue_depth,
                 }),
             },
             shutdown_rx,
@@ -123,14 +175,18 @@ impl BlockingPool {
         &self.spawner
     }
 
-    pub(crate) fn shutdown(&mut self, timeout: Option<Duration>) {
+    /// Shuts down the pool, waiting for at most `timeout` for all worker
+    /// threads to exit. Returns `true` if every thread exited before the
+    /// timeout elapsed, and `false` if the timeout elapsed first (in which
+    /// case the still-running blocking tasks are leaked).
+    pub(crate) fn shutdown(&mut self, timeout: Option<Duration>) -> bool {
         let mut shared = self.spawner.inner.shared.lock();
 
         // The function can be called multiple times. First, by explicitly
         // calling `shutdown` then by the drop handler calling `shutdown`. This
         // prevents shutting down twice.
         if shared.shutdown {
-            return;
+            return true;
         }
 
         shared.shutdown = true;
@@ -142,7 +198,9 @@ impl BlockingPool {
 
         drop(shared);
 
-        if self.shutdown_rx.wait(timeout) {
+        let completed = self.shutdown_rx.wait(timeout);
+
+        if completed {
             let _ = last_exited_thread.map(|th| th.join());
 
             // Loom requires that execution be deterministic, so sort by thread ID before joining.
@@ -154,12 +212,14 @@ impl BlockingPool {
                 let _ = handle.join();
             }
         }
+
+        completed
     }
 }
 
 impl Drop for BlockingPool {
     fn drop(&mut self) {
-        self.shutdown(None);
+        let _ = self.shutdown(None);
     }
 }
 
@@ -172,6 +232,18 @@ impl fmt::Debug for BlockingPool {
 // ===== impl Spawner =====
 
 impl Spawner {
+    #[cfg(tokio_unstable)]
+    pub(crate) fn metrics(&self) -> BlockingPoolMetrics {
+        let shared = self.inner.shared.lock();
+
+        BlockingPoolMetrics {
+            num_threads: shared.num_th,
+            num_idle_threads: shared.num_idle as usize,
+            queue_depth: shared.queue.len(),
+            num_tasks_executed: shared.tasks_executed,
+        }
+    }
+
     pub(crate) fn spawn(&self, task: Task, rt: &Handle) -> Result<(), ()> {
         let shutdown_tx = {
             let mut shared = self.inner.shared.lock();
@@ -184,6 +256,15 @@ impl Spawner {
                 return Err(());
             }
 
+            if let Some(cap) = self.inner.queue_depth_cap {
+                if shared.queue.len() >= cap {
+                    // The queue is at capacity; reject the task instead of
+                    // growing the queue further.
+                    task.shutdown();
+                    return Err(());
+                }
+            }
+
             shared.queue.push_back(task);
 
             if shared.num_idle == 0 {
@@ -261,6 +342,7 @@ impl Inner {
         'main: loop {
             // BUSY
             while let Some(task) = shared.queue.pop_front() {
+                shared.tasks_executed += 1;
                 drop(shared);
                 task.run();
 
@@ -58,6 +58,18 @@ pub struct Builder {
     /// Cap on thread usage.
     max_blocking_threads: usize,
 
+    /// Cap on the number of tasks that may be live (spawned but not yet
+    /// completed) on the runtime at once. `None` means no limit.
+    max_tasks: Option<usize>,
+
+    /// Threshold and callback for the blocked-worker watchdog. `None` means
+    /// the watchdog is disabled. Only consulted by the multi-thread
+    /// scheduler.
+    unresponsive_worker: Option<(
+        Duration,
+        std::sync::Arc<dyn Fn(usize, Duration) + Send + Sync>,
+    )>,
+
     /// Name fn used for threads spawned by the runtime.
     pub(super) thread_name: ThreadNameFn,
 
@@ -72,13 +84,18 @@ pub struct Builder {
 
     /// Customizable keep alive timeout for BlockingPool
     pub(super) keep_alive: Option<Duration>,
+
+    /// Cap on the number of tasks queued for the blocking pool before new
+    /// `spawn_blocking` calls are rejected. `None` means the queue is
+    /// unbounded.
+    pub(super) max_blocking_queue_depth: Option<usize>,
 }
 
 pub(crate) type ThreadNameFn = std::sync::Arc<dyn Fn() -> String + Send + Sync + 'static>;
 
 pub(crate) enum Kind {
     CurrentThread,
-    #[cfg(feature = "rt-multi-thread")]
+    #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
     MultiThread,
 }
 
@@ -98,7 +115,7 @@ impl Builder {
     /// Returns a new builder with the multi thread scheduler selected.
     ///
     /// Configuration methods can be chained on the return value.
-    #[cfg(feature = "rt-multi-thread")]
+    #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
     #[cfg_attr(docsrs, doc(cfg(feature = "rt-multi-thread")))]
     pub fn new_multi_thread() -> Builder {
         Builder::new(Kind::MultiThread)
@@ -126,6 +143,10 @@ impl Builder {
 
             max_blocking_threads: 512,
 
+            // No task cap by default
+            max_tasks: None,
+            unresponsive_worker: None,
+
             // Default thread name
             thread_name: std::sync::Arc::new(|| "tokio-runtime-worker".into()),
 
@@ -137,6 +158,9 @@ impl Builder {
             before_stop: None,
 
             keep_alive: None,
+
+            // No queue depth limit by default; `spawn_blocking` never rejects.
+            max_blocking_queue_depth: None,
         }
     }
 
@@ -248,6 +272,64 @@ impl Builder {
         self
     }
 
+    /// Caps the number of tasks that may be live (spawned but not yet
+    /// completed) on the runtime at the same time.
+    ///
+    /// Once the limit is reached, additional calls to [`spawn`] still return
+    /// a [`JoinHandle`], but the new task is never scheduled to run: it is
+    /// shut down immediately, exactly as if it had been spawned on a runtime
+    /// that was already shutting down. Awaiting that `JoinHandle` yields a
+    /// [`JoinError`], rather than the task's output. This is meant as a
+    /// guard rail against runaway task creation (for example a spawn loop
+    /// with a broken exit condition), not as a backpressure mechanism for
+    /// well-behaved workloads; see [`spawn_with_permit`] if you want tasks
+    /// to wait for capacity instead of being rejected.
+    ///
+    /// The default is no limit.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `val` is not larger than `0`.
+    ///
+    /// [`spawn`]: crate::task::spawn
+    /// [`JoinHandle`]: crate::task::JoinHandle
+    /// [`JoinError`]: crate::task::JoinError
+    /// [`spawn_with_permit`]: crate::runtime::Handle::spawn_with_permit
+    pub fn max_tasks(&mut self, val: usize) -> &mut Self {
+        assert!(val > 0, "Max tasks cannot be set to 0");
+        self.max_tasks = Some(val);
+        self
+    }
+
+    /// Installs a watchdog that calls `callback` whenever a worker thread
+    /// spends at least `threshold` polling a single task without yielding.
+    ///
+    /// This is meant to help diagnose a task that accidentally runs
+    /// synchronous, blocking code directly on a worker (for example a
+    /// std::fs call or a long CPU-bound loop), starving every other task
+    /// assigned to that worker. The callback is invoked with the index of
+    /// the stuck worker and how long it has been stuck so far; it may be
+    /// called repeatedly for the same stall as the watchdog keeps scanning.
+    /// It runs on a dedicated background thread, not on a worker, so it must
+    /// not block or panic.
+    ///
+    /// The watchdog does not track which task is running on a stuck worker;
+    /// pair it with the `tracing` unstable feature's per-task spans to
+    /// correlate a stall with the task that caused it.
+    ///
+    /// Only used by the multi-thread scheduler; ignored when building a
+    /// current-thread runtime, since a stuck current-thread runtime stalls
+    /// the entire program and needs no extra detection.
+    ///
+    /// By default, no watchdog is installed.
+    pub fn on_thread_unresponsive<F>(&mut self, threshold: Duration, callback: F) -> &mut Self
+    where
+        F: Fn(usize, Duration) + Send + Sync + 'static,
+    {
+        self.unresponsive_worker = Some((threshold, std::sync::Arc::new(callback)));
+        self
+    }
+
     /// Sets name of threads spawned by the `Runtime`'s thread pool.
     ///
     /// The default name is "tokio-runtime-worker".
@@ -324,7 +406,16 @@ impl Builder {
     /// Executes function `f` after each thread is started but before it starts
     /// doing work.
     ///
-    /// This is intended for bookkeeping and monitoring use cases.
+    /// This is intended for bookkeeping and monitoring use cases, such as
+    /// installing a thread-local allocator arena, pinning the thread to a
+    /// CPU core, or tagging the thread for a profiler.
+    ///
+    /// On a [`new_multi_thread`](Self::new_multi_thread) runtime, `f` runs on
+    /// every scheduler worker thread, since those threads are themselves
+    /// spawned through the blocking pool's thread-spawning path. It also runs
+    /// on every thread spun up to service [`spawn_blocking`] calls. On a
+    /// [`new_current_thread`](Self::new_current_thread) runtime there are no
+    /// dedicated worker threads, so `f` only runs for the latter.
     ///
     /// # Examples
     ///
@@ -339,6 +430,8 @@ impl Builder {
     ///     .build();
     /// # }
     /// ```
+    ///
+    /// [`spawn_blocking`]: crate::task::spawn_blocking
     #[cfg(not(loom))]
     pub fn on_thread_start<F>(&mut self, f: F) -> &mut Self
     where
@@ -350,7 +443,9 @@ impl Builder {
 
     /// Executes function `f` before each thread stops.
     ///
-    /// This is intended for bookkeeping and monitoring use cases.
+    /// This is intended for bookkeeping and monitoring use cases. See
+    /// [`on_thread_start`](Self::on_thread_start) for which threads `f` runs
+    /// on.
     ///
     /// # Examples
     ///
@@ -392,7 +487,7 @@ impl Builder {
     pub fn build(&mut self) -> io::Result<Runtime> {
         match &self.kind {
             Kind::CurrentThread => self.build_basic_runtime(),
-            #[cfg(feature = "rt-multi-thread")]
+            #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
             Kind::MultiThread => self.build_threaded_runtime(),
         }
     }
@@ -401,7 +496,7 @@ impl Builder {
         driver::Cfg {
             enable_pause_time: match self.kind {
                 Kind::CurrentThread => true,
-                #[cfg(feature = "rt-multi-thread")]
+                #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
                 Kind::MultiThread => false,
             },
             enable_io: self.enable_io,
@@ -432,6 +527,37 @@ impl Builder {
         self
     }
 
+    /// Caps the number of tasks that may be queued for the blocking pool
+    /// waiting for a free thread, rejecting [`spawn_blocking`] calls once the
+    /// cap is reached.
+    ///
+    /// By default the queue is unbounded: if every blocking thread (up to
+    /// [`max_blocking_threads`]) is busy, further blocking tasks simply wait
+    /// in the queue, which can grow without limit if blocking work keeps
+    /// arriving faster than it is drained. Setting a queue depth turns that
+    /// silent, unbounded wait into an explicit rejection: once the limit is
+    /// reached, additional calls to [`spawn_blocking`] still return a
+    /// [`JoinHandle`], but the new task is never scheduled to run — it is
+    /// shut down immediately, exactly as if the blocking pool were already
+    /// shutting down. Awaiting that `JoinHandle` yields a [`JoinError`],
+    /// rather than the closure's output.
+    ///
+    /// The default is no limit.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `val` is not larger than `0`.
+    ///
+    /// [`spawn_blocking`]: fn@crate::task::spawn_blocking
+    /// [`max_blocking_threads`]: Self::max_blocking_threads
+    /// [`JoinHandle`]: crate::task::JoinHandle
+    /// [`JoinError`]: crate::task::JoinError
+    pub fn max_blocking_queue_depth(&mut self, val: usize) -> &mut Self {
+        assert!(val > 0, "Max blocking queue depth cannot be set to 0");
+        self.max_blocking_queue_depth = Some(val);
+        self
+    }
+
     fn build_basic_runtime(&mut self) -> io::Result<Runtime> {
         use crate::runtime::{BasicScheduler, Kind};
 
@@ -441,7 +567,7 @@ impl Builder {
         // there are no futures ready to do something, it'll let the timer or
         // the reactor to generate some new stimuli for the futures to continue
         // in their life.
-        let scheduler = BasicScheduler::new(driver);
+        let scheduler = BasicScheduler::new(driver, self.max_tasks.unwrap_or(usize::MAX));
         let spawner = Spawner::Basic(scheduler.spawner().clone());
 
         // Blocking pool
@@ -541,12 +667,23 @@ cfg_rt_multi_thread! {
             use crate::loom::sys::num_cpus;
             use crate::runtime::{Kind, ThreadPool};
             use crate::runtime::park::Parker;
+            use crate::runtime::thread_pool::Watchdog;
 
             let core_threads = self.worker_threads.unwrap_or_else(num_cpus);
 
             let (driver, resources) = driver::Driver::new(self.get_cfg())?;
 
-            let (scheduler, launch) = ThreadPool::new(core_threads, Parker::new(driver));
+            let watchdog = self
+                .unresponsive_worker
+                .as_ref()
+                .map(|(threshold, callback)| std::sync::Arc::new(Watchdog::new(*threshold, callback.clone())));
+
+            let (scheduler, launch) = ThreadPool::new(
+                core_threads,
+                Parker::new(driver),
+                self.max_tasks.unwrap_or(usize::MAX),
+                watchdog,
+            );
             let spawner = Spawner::ThreadPool(scheduler.spawner().clone());
 
             // Create the blocking pool
@@ -111,6 +111,49 @@ impl Handle {
         context::current().ok_or(TryCurrentError(()))
     }
 
+    /// Spawns `future` onto the runtime that is currently active, if any,
+    /// and otherwise drops it without running any of it.
+    ///
+    /// This is a convenience wrapper around [`Handle::try_current`] and
+    /// [`Handle::spawn`], for callers that want to opportunistically hand a
+    /// future off to a runtime without caring whether one happens to be
+    /// running, and without risking a panic if it isn't. This makes it safe
+    /// to call from a `Drop` implementation or other destructor, where the
+    /// ambient runtime may already be gone (or may never have existed in the
+    /// first place, if the value outlives any runtime) by the time the drop
+    /// runs.
+    ///
+    /// Because there is no guarantee a runtime is present, the spawned
+    /// future's output is discarded; use [`Handle::spawn`] directly if you
+    /// need the resulting [`JoinHandle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::runtime::Handle;
+    ///
+    /// struct NotifiesOnDrop;
+    ///
+    /// impl Drop for NotifiesOnDrop {
+    ///     fn drop(&mut self) {
+    ///         // Whether or not a runtime happens to be alive right now,
+    ///         // this will never panic.
+    ///         Handle::defer_spawn(async {
+    ///             println!("cleaning up");
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    pub fn defer_spawn<F>(future: F)
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        if let Ok(handle) = Handle::try_current() {
+            let _ = handle.spawn(future);
+        }
+    }
+
     /// Spawn a future onto the Tokio runtime.
     ///
     /// This spawns the given future onto the runtime's executor, usually a
@@ -177,6 +220,116 @@ impl Handle {
         self.spawn_blocking_inner(func, None)
     }
 
+    /// Returns a snapshot of the blocking pool's current state: how many
+    /// threads are alive, how many are idle, how deep the queue of tasks
+    /// waiting for a thread is, and how many blocking tasks have run so far.
+    ///
+    /// Pair this with [`Builder::max_blocking_queue_depth`] if you've seen
+    /// `spawn_blocking` calls pile up with no visibility into why.
+    ///
+    /// [`Builder::max_blocking_queue_depth`]: crate::runtime::Builder::max_blocking_queue_depth
+    #[cfg(tokio_unstable)]
+    #[cfg_attr(docsrs, doc(cfg(tokio_unstable)))]
+    pub fn blocking_pool_metrics(&self) -> crate::runtime::BlockingPoolMetrics {
+        self.blocking_spawner.metrics()
+    }
+
+    /// Spawns a future onto the Tokio runtime, but only once a permit has
+    /// been acquired from `semaphore`.
+    ///
+    /// The permit is acquired on the runtime, after the task has been
+    /// scheduled but before `future` starts running, and is held for as
+    /// long as `future` is still executing. It is released as soon as the
+    /// task completes, whether that is by finishing normally, panicking, or
+    /// being [aborted]. This makes it straightforward to cap the number of
+    /// tasks of some kind that are in flight at once, without having to
+    /// thread the acquire/release calls through the body of every task.
+    ///
+    /// [aborted]: crate::task::JoinHandle::abort
+    ///
+    /// If `semaphore` is [closed](crate::sync::Semaphore::close) while the
+    /// task is still waiting for a permit, `future` is never polled and the
+    /// returned [`JoinHandle`] resolves to `Ok(Err(AcquireError))` instead of
+    /// running it. This lets callers close the semaphore as part of a
+    /// graceful shutdown without panicking every task still queued on it.
+    ///
+    /// [`JoinHandle`]: crate::task::JoinHandle
+    ///
+    /// Note that `spawn_with_permit` itself returns immediately: the task is
+    /// spawned right away and only *waits to run* for the permit. If
+    /// producers are creating tasks faster than they can be drained — for
+    /// example a network listener accepting connections faster than they can
+    /// be handled — that still grows memory without bound, because nothing
+    /// ever stops the producer from spawning more. To get backpressure on
+    /// the producer itself, `acquire_owned` the permit *before* spawning and
+    /// move it into a plain spawned task, so the accept loop stalls until a
+    /// slot frees up:
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use tokio::runtime::Runtime;
+    /// use tokio::sync::Semaphore;
+    ///
+    /// # fn dox() {
+    /// let rt = Runtime::new().unwrap();
+    /// let handle = rt.handle();
+    /// let limiter = Arc::new(Semaphore::new(10));
+    ///
+    /// # async fn accept() { }
+    /// # async fn handle_connection() { }
+    /// handle.spawn(async move {
+    ///     loop {
+    ///         accept().await;
+    ///         // Blocks the accept loop itself once 10 connections are
+    ///         // already being handled, instead of letting the queue of
+    ///         // in-flight connections grow without bound.
+    ///         let permit = limiter.clone().acquire_owned().await.unwrap();
+    ///         tokio::spawn(async move {
+    ///             handle_connection().await;
+    ///             drop(permit);
+    ///         });
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use tokio::runtime::Runtime;
+    /// use tokio::sync::Semaphore;
+    ///
+    /// # fn dox() {
+    /// let rt = Runtime::new().unwrap();
+    /// let handle = rt.handle();
+    ///
+    /// // Allow at most 10 of these tasks to run at the same time.
+    /// let limiter = Arc::new(Semaphore::new(10));
+    ///
+    /// handle.spawn_with_permit(limiter.clone(), async {
+    ///     println!("running with a permit held");
+    /// });
+    /// # }
+    /// ```
+    #[cfg(feature = "sync")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+    #[cfg_attr(tokio_track_caller, track_caller)]
+    pub fn spawn_with_permit<F>(
+        &self,
+        semaphore: std::sync::Arc<crate::sync::Semaphore>,
+        future: F,
+    ) -> JoinHandle<Result<F::Output, crate::sync::AcquireError>>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.spawn(async move {
+            let _permit = semaphore.acquire_owned().await?;
+            Ok(future.await)
+        })
+    }
+
     #[cfg_attr(tokio_track_caller, track_caller)]
     pub(crate) fn spawn_blocking_inner<F, R>(&self, func: F, name: Option<&str>) -> JoinHandle<R>
     where
@@ -160,7 +160,23 @@
 //! idle. Once `Runtime` is dropped, all runtime threads are forcibly shutdown.
 //! Any tasks that have not yet completed will be dropped.
 //!
+//! ## Observing scheduler behavior
+//!
+//! Building `tokio` with `--cfg tokio_unstable` and the `tracing` feature
+//! instruments task spawns and polls with [`tracing`] spans under the
+//! `tokio::task` target (see the [`tracing` feature flag] docs). Producing a
+//! `chrome://tracing`-style timeline, or any other offline view of runtime
+//! behavior, is a matter of attaching a `tracing` [`Subscriber`] or [`Layer`]
+//! that records those spans in whatever format is useful, such as the
+//! `tracing-chrome` crate. The runtime itself does not buffer or serialize
+//! scheduler events directly, so that the event format can evolve (and be
+//! swapped) without changing this crate's public API.
+//!
 //! [tasks]: crate::task
+//! [`tracing`]: https://docs.rs/tracing
+//! [`tracing` feature flag]: ../index.html#unstable-features
+//! [`Subscriber`]: https://docs.rs/tracing/latest/tracing/trait.Subscriber.html
+//! [`Layer`]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/layer/trait.Layer.html
 //! [`Runtime`]: Runtime
 //! [`tokio::spawn`]: crate::spawn
 //! [`tokio::main`]: ../attr.main.html
@@ -171,6 +187,87 @@
 //! [`Builder::enable_io`]: crate::runtime::Builder::enable_io
 //! [`Builder::enable_time`]: crate::runtime::Builder::enable_time
 //! [`Builder::enable_all`]: crate::runtime::Builder::enable_all
+//!
+//! ## Determinism and simulated drivers
+//!
+//! A [runtime builder] that never calls [`Builder::enable_io`] or
+//! [`Builder::enable_time`] already parks on [`thread::park`] alone, with
+//! no `mio`-based I/O driver or timer wheel running underneath it — that
+//! much of "driver-less" is just the current-thread scheduler's default.
+//! What it does not give you is a way to replace that park point with a
+//! *simulated* driver you control, so that a multi-node test harness can
+//! single-step every node's scheduler under a seed.
+//!
+//! The scheduler is already generic over a [`Park`] implementation
+//! internally (`BasicScheduler<P>`), but `Park` is a `pub(crate)` seam
+//! between the scheduler and `tokio`'s own thread/IO/time parkers, not a
+//! stable public trait: `Runtime` and `Builder` hard-code the concrete
+//! driver stack they assemble, rather than taking `P` as a type parameter.
+//! Exposing a pluggable `Park` would mean committing to its shape (the
+//! `park`/`park_timeout`/`unpark` contract, and how it composes with the
+//! `Unpark` half used to wake tasks from other threads) as public API, and
+//! threading that type parameter through `Runtime`, `Handle`, and
+//! `Spawner`. That is a larger, deliberate design change rather than
+//! something to bolt on as a side effect of an unrelated feature, so for
+//! now simulated execution is best built on top of [`Builder::start_paused`]
+//! plus manual [`time::advance`] calls, which already gives deterministic
+//! control over when timers fire without needing a custom driver.
+//!
+//! [`Park`]: crate::park::Park
+//! [`thread::park`]: std::thread::park
+//! [`Builder::start_paused`]: crate::runtime::Builder::start_paused
+//! [`time::advance`]: crate::time::advance
+//!
+//! ## Task priorities
+//!
+//! The multi-threaded scheduler gives every worker a LIFO slot, a local
+//! run queue, and access to stealing from other workers' local queues, but
+//! none of those queues are priority-ordered: a worker always drains its
+//! LIFO slot first, then its local queue in FIFO order, then steals, with
+//! no notion of one `Notified` task mattering more than another. There is
+//! no `spawn_with_priority` and no way to mark a worker's local queue or
+//! steal attempts as preferring one task over another.
+//!
+//! Retrofitting priority onto the existing queues isn't a small addition:
+//! the local run queue and the cross-worker steal path are a single
+//! lock-free, fixed-capacity ring buffer shared by the owning worker and
+//! every thief (see `runtime::queue`), so "prefer high
+//! priority" would mean redesigning that data structure to carry two
+//! (or more) independently steal-safe queues per worker, plus deciding
+//! how a steal should weigh an empty high-priority queue against a full
+//! low-priority one on someone else's worker. That's a scheduler redesign,
+//! not something to bolt on as a side effect of an unrelated feature.
+//!
+//! Until then, the usual way to keep latency-sensitive work from queueing
+//! behind batch work is to give them separate [`Runtime`]s (for example, a
+//! small dedicated runtime for RPC handlers and a larger one for batch
+//! jobs), or to run batch work through [`task::spawn_blocking`] so it
+//! can't occupy an async worker's queue at all.
+//!
+//! [`task::spawn_blocking`]: crate::task::spawn_blocking
+//!
+//! ## Pinning work to a subset of workers
+//!
+//! There is likewise no way to confine a task, or a group of tasks, to a
+//! subset of one [`Runtime`]'s worker threads — something like
+//! `spawn_pinned_to_worker(idx, future)` or a "runtime group" carved out of
+//! a larger pool. Every worker shares the same injection queue and steals
+//! from every other worker's local queue (see `runtime::thread_pool::worker`),
+//! so a task has no durable affinity to the worker it was spawned from: it
+//! can be stolen and polled on any other worker the moment it's woken. Worker
+//! affinity would mean threading a "these workers only" mask through the
+//! inject queue, the steal loop, and the idle-worker wakeup path, which is
+//! the same class of scheduler change as task priorities, above, not a
+//! parameter to add to `spawn`.
+//!
+//! What separate [`Runtime`]s genuinely buy you beyond this — besides being
+//! the existing, supported way to isolate latency-critical work — is that
+//! each one gets its own I/O and timer driver, which duplicates epoll/kqueue
+//! registrations and timer wheels. If that duplication is the actual cost
+//! you're trying to avoid rather than worker isolation itself, measure it
+//! first: a second driver is normally far cheaper than the head-of-line
+//! blocking it prevents.
+//!
 
 // At the top due to macros
 #[cfg(test)]
@@ -188,6 +285,8 @@ cfg_rt! {
     mod blocking;
     use blocking::BlockingPool;
     pub(crate) use blocking::spawn_blocking;
+    #[cfg(tokio_unstable)]
+    pub use blocking::BlockingPoolMetrics;
 
     mod builder;
     pub use self::builder::Builder;
@@ -279,7 +378,7 @@ cfg_rt! {
         CurrentThread(BasicScheduler<driver::Driver>),
 
         /// Execute tasks across multiple threads.
-        #[cfg(feature = "rt-multi-thread")]
+        #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
         ThreadPool(ThreadPool),
     }
 
@@ -316,7 +415,7 @@ cfg_rt! {
         /// [threaded scheduler]: index.html#threaded-scheduler
         /// [basic scheduler]: index.html#basic-scheduler
         /// [runtime builder]: crate::runtime::Builder
-        #[cfg(feature = "rt-multi-thread")]
+        #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
         #[cfg_attr(docsrs, doc(cfg(feature = "rt-multi-thread")))]
         pub fn new() -> std::io::Result<Runtime> {
             Builder::new_multi_thread().enable_all().build()
@@ -448,7 +547,7 @@ cfg_rt! {
 
             match &self.kind {
                 Kind::CurrentThread(exec) => exec.block_on(future),
-                #[cfg(feature = "rt-multi-thread")]
+                #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
                 Kind::ThreadPool(exec) => exec.block_on(future),
             }
         }
@@ -503,6 +602,13 @@ cfg_rt! {
         /// `timeout` elapses before all tasks are dropped, the function returns and
         /// outstanding tasks are potentially leaked.
         ///
+        /// If you need to know whether the deadline was actually hit, use
+        /// [`shutdown_timeout_status`] instead, which reports the same thing
+        /// `shutdown_timeout` does but returns whether every task terminated
+        /// in time rather than discarding that information.
+        ///
+        /// [`shutdown_timeout_status`]: Runtime::shutdown_timeout_status
+        ///
         /// # Examples
         ///
         /// ```
@@ -530,6 +636,42 @@ cfg_rt! {
             self.blocking_pool.shutdown(Some(duration));
         }
 
+        /// Shuts down the runtime, waiting for at most `duration` for all spawned
+        /// tasks to shutdown, and reports whether they all finished in time.
+        ///
+        /// This is identical to [`shutdown_timeout`](Runtime::shutdown_timeout),
+        /// except it returns `true` if every spawned blocking task terminated
+        /// before `duration` elapsed, and `false` if the deadline was hit first
+        /// and some tasks may have been leaked.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Runtime;
+        /// use tokio::task;
+        ///
+        /// use std::thread;
+        /// use std::time::Duration;
+        ///
+        /// fn main() {
+        ///    let runtime = Runtime::new().unwrap();
+        ///
+        ///    runtime.block_on(async move {
+        ///        task::spawn_blocking(move || {
+        ///            thread::sleep(Duration::from_secs(10_000));
+        ///        });
+        ///    });
+        ///
+        ///    let completed = runtime.shutdown_timeout_status(Duration::from_millis(100));
+        ///    assert!(!completed);
+        /// }
+        /// ```
+        pub fn shutdown_timeout_status(mut self, duration: Duration) -> bool {
+            // Wakeup and shutdown all the worker threads
+            self.handle.shutdown();
+            self.blocking_pool.shutdown(Some(duration))
+        }
+
         /// Shutdown the runtime, without waiting for any spawned tasks to shutdown.
         ///
         /// This can be useful if you want to drop a runtime from within another runtime.
@@ -557,7 +699,7 @@ cfg_rt! {
         /// }
         /// ```
         pub fn shutdown_background(self) {
-            self.shutdown_timeout(Duration::from_nanos(0))
+            self.shutdown_timeout(Duration::from_nanos(0));
         }
     }
 }
@@ -195,6 +195,34 @@ impl<T> JoinHandle<T> {
             raw.remote_abort();
         }
     }
+
+    /// Returns an [`AbortHandle`] that can be used to remotely abort this task.
+    ///
+    /// Unlike the [`abort`](Self::abort) method, this can be cloned and
+    /// stored separately from the `JoinHandle`, so the task can still be
+    /// cancelled after the `JoinHandle` itself has been awaited or dropped.
+    ///
+    /// ```rust
+    /// use tokio::time;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let join_handle = tokio::spawn(async {
+    ///        time::sleep(time::Duration::from_secs(10)).await;
+    ///        true
+    ///    });
+    ///
+    ///    let abort_handle = join_handle.abort_handle();
+    ///    drop(join_handle);
+    ///
+    ///    abort_handle.abort();
+    /// }
+    /// ```
+    pub fn abort_handle(&self) -> AbortHandle {
+        let raw = self.raw.expect("`JoinHandle` should contain a task");
+        raw.ref_inc();
+        AbortHandle { raw }
+    }
 }
 
 impl<T> Unpin for JoinHandle<T> {}
@@ -258,3 +286,53 @@ where
         fmt.debug_struct("JoinHandle").finish()
     }
 }
+
+cfg_rt! {
+    /// An owned permission to abort a spawned task, without awaiting its
+    /// completion.
+    ///
+    /// Unlike a [`JoinHandle`], an `AbortHandle` does not provide a way to
+    /// wait for the task to complete, only a way to request that it stop
+    /// running. It can be cloned, and it can be kept around after the
+    /// corresponding `JoinHandle` has been awaited or dropped, which makes it
+    /// useful for cancelling a task from a different part of the program than
+    /// the one that spawned it.
+    ///
+    /// This is created using the [`JoinHandle::abort_handle`] method.
+    pub struct AbortHandle {
+        raw: RawTask,
+    }
+}
+
+unsafe impl Send for AbortHandle {}
+unsafe impl Sync for AbortHandle {}
+
+impl AbortHandle {
+    /// Abort the task associated with this `AbortHandle`.
+    ///
+    /// Awaiting a cancelled task might complete as usual if the task was
+    /// already completed at the time it was cancelled, but most likely it
+    /// will complete with a `Err(JoinError::Cancelled)`.
+    pub fn abort(&self) {
+        self.raw.remote_abort();
+    }
+}
+
+impl Clone for AbortHandle {
+    fn clone(&self) -> Self {
+        self.raw.ref_inc();
+        AbortHandle { raw: self.raw }
+    }
+}
+
+impl Drop for AbortHandle {
+    fn drop(&mut self) {
+        self.raw.drop_abort_handle();
+    }
+}
+
+impl fmt::Debug for AbortHandle {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("AbortHandle").finish()
+    }
+}
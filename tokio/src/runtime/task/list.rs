@@ -55,10 +55,16 @@ cfg_not_has_atomic_u64! {
 pub(crate) struct OwnedTasks<S: 'static> {
     inner: Mutex<OwnedTasksInner<S>>,
     id: u64,
+    /// Maximum number of tasks this collection will bind at once. Tasks
+    /// spawned once the limit is reached are shut down immediately, the
+    /// same way a task spawned after `close()` is, rather than being
+    /// scheduled to run. `usize::MAX` means "no limit".
+    max_tasks: usize,
 }
 struct OwnedTasksInner<S: 'static> {
     list: LinkedList<Task<S>, <Task<S> as Link>::Target>,
     closed: bool,
+    count: usize,
 }
 
 pub(crate) struct LocalOwnedTasks<S: 'static> {
@@ -69,13 +75,20 @@ pub(crate) struct LocalOwnedTasks<S: 'static> {
 }
 
 impl<S: 'static> OwnedTasks<S> {
-    pub(crate) fn new() -> Self {
+    /// Creates a new `OwnedTasks` that caps the number of tasks that can be
+    /// bound to this collection at once. Binding a task past the limit
+    /// shuts it down immediately instead of scheduling it, exactly as
+    /// binding a task after [`OwnedTasks::close`] does. Pass `usize::MAX`
+    /// for no limit.
+    pub(crate) fn with_max_tasks(max_tasks: usize) -> Self {
         Self {
             inner: Mutex::new(OwnedTasksInner {
                 list: LinkedList::new(),
                 closed: false,
+                count: 0,
             }),
             id: get_next_id(),
+            max_tasks,
         }
     }
 
@@ -100,12 +113,13 @@ impl<S: 'static> OwnedTasks<S> {
         }
 
         let mut lock = self.inner.lock();
-        if lock.closed {
+        if lock.closed || lock.count >= self.max_tasks {
             drop(lock);
             drop(notified);
             task.shutdown();
             (join, None)
         } else {
+            lock.count += 1;
             lock.list.push_front(task);
             (join, Some(notified))
         }
@@ -126,7 +140,12 @@ impl<S: 'static> OwnedTasks<S> {
     }
 
     pub(crate) fn pop_back(&self) -> Option<Task<S>> {
-        self.inner.lock().list.pop_back()
+        let mut lock = self.inner.lock();
+        let task = lock.list.pop_back();
+        if task.is_some() {
+            lock.count -= 1;
+        }
+        task
     }
 
     pub(crate) fn remove(&self, task: &Task<S>) -> Option<Task<S>> {
@@ -140,14 +159,19 @@ impl<S: 'static> OwnedTasks<S> {
 
         // safety: We just checked that the provided task is not in some other
         // linked list.
-        unsafe { self.inner.lock().list.remove(task.header().into()) }
+        let mut lock = self.inner.lock();
+        let removed = unsafe { lock.list.remove(task.header().into()) };
+        if removed.is_some() {
+            lock.count -= 1;
+        }
+        removed
     }
 
     pub(crate) fn is_empty(&self) -> bool {
         self.inner.lock().list.is_empty()
     }
 
-    #[cfg(feature = "rt-multi-thread")]
+    #[cfg(all(feature = "rt-multi-thread", not(target_arch = "wasm32")))]
     pub(crate) fn is_closed(&self) -> bool {
         self.inner.lock().closed
     }
@@ -16,7 +16,7 @@ cfg_rt_multi_thread! {
 
 mod join;
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/57411
-pub use self::join::JoinHandle;
+pub use self::join::{AbortHandle, JoinHandle};
 
 mod list;
 pub(crate) use self::list::{LocalOwnedTasks, OwnedTasks};
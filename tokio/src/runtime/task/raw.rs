@@ -25,6 +25,9 @@ pub(super) struct Vtable {
     /// The task is remotely aborted
     pub(super) remote_abort: unsafe fn(NonNull<Header>),
 
+    /// An `AbortHandle` referencing this task has been dropped
+    pub(super) drop_abort_handle: unsafe fn(NonNull<Header>),
+
     /// Scheduler is being shutdown
     pub(super) shutdown: unsafe fn(NonNull<Header>),
 }
@@ -37,6 +40,7 @@ pub(super) fn vtable<T: Future, S: Schedule>() -> &'static Vtable {
         try_read_output: try_read_output::<T, S>,
         drop_join_handle_slow: drop_join_handle_slow::<T, S>,
         remote_abort: remote_abort::<T, S>,
+        drop_abort_handle: drop_abort_handle::<T, S>,
         shutdown: shutdown::<T, S>,
     }
 }
@@ -98,6 +102,16 @@ impl RawTask {
         let vtable = self.header().vtable;
         unsafe { (vtable.remote_abort)(self.ptr) }
     }
+
+    /// Increments the task's reference count, for a new `AbortHandle`.
+    pub(super) fn ref_inc(self) {
+        self.header().state.ref_inc();
+    }
+
+    pub(super) fn drop_abort_handle(self) {
+        let vtable = self.header().vtable;
+        unsafe { (vtable.drop_abort_handle)(self.ptr) }
+    }
 }
 
 impl Clone for RawTask {
@@ -139,6 +153,11 @@ unsafe fn remote_abort<T: Future, S: Schedule>(ptr: NonNull<Header>) {
     harness.remote_abort()
 }
 
+unsafe fn drop_abort_handle<T: Future, S: Schedule>(ptr: NonNull<Header>) {
+    let harness = Harness::<T, S>::from_raw(ptr);
+    harness.drop_reference()
+}
+
 unsafe fn shutdown<T: Future, S: Schedule>(ptr: NonNull<Header>) {
     let harness = Harness::<T, S>::from_raw(ptr);
     harness.shutdown()
@@ -240,7 +240,7 @@ fn test_combination(
 
         // If the task gets past wait_complete without yielding, then aborts
         // may not be caught without this yield_now.
-        crate::task::yield_now().await;
+        let _ = crate::task::yield_now().await;
 
         if task == CombiTask::PanicOnRun || task == CombiTask::PanicOnRunAndDrop {
             panic!("Panicking in my_task on {:?}", std::thread::current().id());
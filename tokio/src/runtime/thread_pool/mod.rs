@@ -11,6 +11,9 @@ pub(crate) use worker::Launch;
 
 pub(crate) use worker::block_in_place;
 
+mod watchdog;
+pub(crate) use watchdog::Watchdog;
+
 use crate::loom::sync::Arc;
 use crate::runtime::task::JoinHandle;
 use crate::runtime::Parker;
@@ -43,8 +46,13 @@ pub(crate) struct Spawner {
 // ===== impl ThreadPool =====
 
 impl ThreadPool {
-    pub(crate) fn new(size: usize, parker: Parker) -> (ThreadPool, Launch) {
-        let (shared, launch) = worker::create(size, parker);
+    pub(crate) fn new(
+        size: usize,
+        parker: Parker,
+        max_tasks: usize,
+        watchdog: Option<std::sync::Arc<Watchdog>>,
+    ) -> (ThreadPool, Launch) {
+        let (shared, launch) = worker::create(size, parker, max_tasks, watchdog);
         let spawner = Spawner { shared };
         let thread_pool = ThreadPool { spawner };
 
@@ -0,0 +1,91 @@
+//! An opt-in watchdog that detects a worker stuck inside a single task poll
+//! for longer than a configured threshold — the usual symptom of synchronous,
+//! blocking code accidentally running on a worker thread.
+//!
+//! The watchdog only tracks *how long* and *which worker*; it does not
+//! duplicate the task-name/spawn-location bookkeeping that the `tracing`
+//! unstable feature already attaches to every task (see
+//! [`crate::util::trace`]). Correlating a blocked worker with the task
+//! running on it is expected to go through those `tokio::task` spans.
+
+// This module spawns a real background thread that sleeps on a wall-clock
+// timer; it is not part of any loom-tested execution path (loom's model
+// checker does not exercise opt-in instrumentation threads), so it talks to
+// `std::sync`/`std::thread` directly rather than through `crate::loom`.
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub(crate) type UnresponsiveCallback = Arc<dyn Fn(usize, Duration) + Send + Sync>;
+
+/// Configuration for the blocked-worker watchdog, shared (read-only, aside
+/// from `PollTimer`s) across all workers in a pool.
+pub(crate) struct Watchdog {
+    threshold: Duration,
+    callback: UnresponsiveCallback,
+}
+
+impl Watchdog {
+    pub(crate) fn new(threshold: Duration, callback: UnresponsiveCallback) -> Watchdog {
+        Watchdog { threshold, callback }
+    }
+}
+
+/// Per-worker record of when the worker started polling its current task, if
+/// any. Cheap to update: a single uncontended lock held for the duration of
+/// one store.
+#[derive(Default)]
+pub(crate) struct PollTimer {
+    started_at: Mutex<Option<Instant>>,
+}
+
+impl PollTimer {
+    pub(crate) fn start(&self) {
+        *self.started_at.lock().unwrap() = Some(Instant::now());
+    }
+
+    pub(crate) fn stop(&self) {
+        *self.started_at.lock().unwrap() = None;
+    }
+
+    fn blocked_for(&self) -> Option<Duration> {
+        (*self.started_at.lock().unwrap()).map(|started_at| started_at.elapsed())
+    }
+}
+
+/// Spawns the background thread that periodically scans `poll_timers` and
+/// invokes `watchdog`'s callback for any worker that has been stuck in a
+/// single poll for longer than the configured threshold.
+///
+/// The thread holds only a [`Weak`] reference to the timers, and exits once
+/// they are gone, so it does not keep the pool alive on its own.
+pub(crate) fn spawn(watchdog: Arc<Watchdog>, poll_timers: &Arc<[PollTimer]>) {
+    let poll_timers = Arc::downgrade(poll_timers);
+    // Scan a few times per threshold so a stall is reported promptly without
+    // spinning needlessly on long thresholds.
+    let interval = watchdog.threshold / 4;
+
+    let _ = thread::Builder::new()
+        .name("tokio-runtime-watchdog".into())
+        .spawn(move || run(watchdog, poll_timers, interval));
+}
+
+fn run(watchdog: Arc<Watchdog>, poll_timers: Weak<[PollTimer]>, interval: Duration) {
+    loop {
+        thread::sleep(interval.max(Duration::from_millis(1)));
+
+        let poll_timers = match poll_timers.upgrade() {
+            Some(poll_timers) => poll_timers,
+            // The pool has been dropped; nothing left to watch.
+            None => return,
+        };
+
+        for (index, timer) in poll_timers.iter().enumerate() {
+            if let Some(blocked_for) = timer.blocked_for() {
+                if blocked_for >= watchdog.threshold {
+                    (watchdog.callback)(index, blocked_for);
+                }
+            }
+        }
+    }
+}
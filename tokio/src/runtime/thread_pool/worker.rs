@@ -65,6 +65,7 @@ use crate::runtime;
 use crate::runtime::enter::EnterContext;
 use crate::runtime::park::{Parker, Unparker};
 use crate::runtime::task::{Inject, JoinHandle, OwnedTasks};
+use crate::runtime::thread_pool::watchdog::{self, PollTimer, Watchdog};
 use crate::runtime::thread_pool::{AtomicCell, Idle};
 use crate::runtime::{queue, task};
 use crate::util::FastRand;
@@ -137,6 +138,11 @@ pub(super) struct Shared {
     /// stolen by a thread that was spawned as part of `block_in_place`.
     #[allow(clippy::vec_box)] // we're moving an already-boxed value
     shutdown_cores: Mutex<Vec<Box<Core>>>,
+
+    /// One [`PollTimer`] per worker, used by the watchdog to notice a worker
+    /// stuck in a single task poll. Only allocated when a watchdog is
+    /// actually configured, so pools that don't opt in pay nothing for it.
+    poll_timers: Option<std::sync::Arc<[PollTimer]>>,
 }
 
 /// Used to communicate with a worker from other threads.
@@ -174,7 +180,12 @@ type Notified = task::Notified<Arc<Shared>>;
 // Tracks thread-local state
 scoped_thread_local!(static CURRENT: Context);
 
-pub(super) fn create(size: usize, park: Parker) -> (Arc<Shared>, Launch) {
+pub(super) fn create(
+    size: usize,
+    park: Parker,
+    max_tasks: usize,
+    watchdog: Option<std::sync::Arc<Watchdog>>,
+) -> (Arc<Shared>, Launch) {
     let mut cores = vec![];
     let mut remotes = vec![];
 
@@ -198,12 +209,24 @@ pub(super) fn create(size: usize, park: Parker) -> (Arc<Shared>, Launch) {
         remotes.push(Remote { steal, unpark });
     }
 
+    let poll_timers: Option<std::sync::Arc<[PollTimer]>> = watchdog.as_ref().map(|_| {
+        (0..size)
+            .map(|_| PollTimer::default())
+            .collect::<Vec<_>>()
+            .into()
+    });
+
+    if let (Some(watchdog), Some(poll_timers)) = (watchdog, &poll_timers) {
+        watchdog::spawn(watchdog, poll_timers);
+    }
+
     let shared = Arc::new(Shared {
         remotes: remotes.into_boxed_slice(),
         inject: Inject::new(),
         idle: Idle::new(size),
-        owned: OwnedTasks::new(),
+        owned: OwnedTasks::with_max_tasks(max_tasks),
         shutdown_cores: Mutex::new(vec![]),
+        poll_timers,
     });
 
     let mut launch = Launch(vec![]);
@@ -395,7 +418,9 @@ impl Context {
 
         // Run the task
         coop::budget(|| {
+            self.poll_timer_start();
             task.run();
+            self.poll_timer_stop();
 
             // As long as there is budget remaining and a task exists in the
             // `lifo_slot`, then keep running.
@@ -417,7 +442,9 @@ impl Context {
                     // Run the LIFO task, then loop
                     *self.core.borrow_mut() = Some(core);
                     let task = self.worker.shared.owned.assert_owner(task);
+                    self.poll_timer_start();
                     task.run();
+                    self.poll_timer_stop();
                 } else {
                     // Not enough budget left to run the LIFO task, push it to
                     // the back of the queue and return.
@@ -428,6 +455,18 @@ impl Context {
         })
     }
 
+    fn poll_timer_start(&self) {
+        if let Some(poll_timers) = &self.worker.shared.poll_timers {
+            poll_timers[self.worker.index].start();
+        }
+    }
+
+    fn poll_timer_stop(&self) {
+        if let Some(poll_timers) = &self.worker.shared.poll_timers {
+            poll_timers[self.worker.index].stop();
+        }
+    }
+
     fn maintenance(&self, mut core: Box<Core>) -> Box<Core> {
         if core.tick % GLOBAL_POLL_INTERVAL == 0 {
             // Call `park` with a 0 timeout. This enables the I/O driver, timer, ...
@@ -33,6 +33,40 @@ use std::io;
 /// Thus, applications should take care to ensure the expected signal behavior
 /// occurs as expected after listening for specific signals.
 ///
+/// # Multiple listeners
+///
+/// Every call to `ctrl_c()` registers its own, independent listener, so it's
+/// fine to `.await` it concurrently from more than one task: each call
+/// receives its own notification of a "ctrl-c" and they don't steal events
+/// from each other. This also means `ctrl_c()` can be called again, and
+/// again, after a previous call has completed — each `.await` only ever
+/// observes "ctrl-c" events that arrive after that particular call started
+/// listening, so a second call won't immediately resolve using an event the
+/// first call already consumed.
+///
+/// This makes it straightforward to build the common "press ctrl-c twice to
+/// force quit" pattern, by waiting for a second "ctrl-c" concurrently with
+/// an in-progress graceful shutdown:
+///
+/// ```rust,no_run
+/// use tokio::signal;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     signal::ctrl_c().await.expect("failed to listen for event");
+///     println!("graceful shutdown started, press ctrl-c again to force quit");
+///
+///     tokio::select! {
+///         _ = graceful_shutdown() => {}
+///         _ = signal::ctrl_c() => println!("forcing shutdown"),
+///     }
+/// }
+///
+/// async fn graceful_shutdown() {
+///     // ...
+/// }
+/// ```
+///
 /// # Examples
 ///
 /// ```rust,no_run
@@ -22,9 +22,12 @@ use self::driver::Handle;
 
 pub(crate) type OsStorage = Vec<SignalInfo>;
 
-// Number of different unix signals
-// (FreeBSD has 33)
-const SIGNUM: usize = 33;
+// Number of different unix signals. This needs to be large enough to
+// accommodate the highest real-time signal number, since `SignalKind` just
+// stores a raw signal number and storage is indexed directly by it; 65
+// covers `SIGRTMAX` (64 on Linux) with room to spare on other platforms
+// (FreeBSD only goes up to 33).
+const SIGNUM: usize = 65;
 
 impl Init for OsStorage {
     fn init() -> Self {
@@ -188,6 +191,24 @@ impl SignalKind {
     pub fn window_change() -> Self {
         Self(libc::SIGWINCH)
     }
+
+    /// Represents a real-time signal, identified by its offset from
+    /// `SIGRTMIN`.
+    ///
+    /// Unlike the fixed signals above, real-time signals don't have
+    /// portable names: `SIGRTMIN+3`, say, means whatever the application or
+    /// service integration that sends it says it means (systemd's watchdog
+    /// and notify protocols are common sources). `offset` is clamped to the
+    /// `[0, SIGRTMAX - SIGRTMIN]` range reported by libc at runtime, so an
+    /// out-of-range offset saturates to `SIGRTMAX` rather than wrapping
+    /// into the fixed signals below `SIGRTMIN`.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[cfg_attr(docsrs, doc(cfg(any(target_os = "linux", target_os = "android"))))]
+    pub fn realtime(offset: u8) -> Self {
+        let rtmin = libc::SIGRTMIN();
+        let rtmax = libc::SIGRTMAX();
+        Self((rtmin + i32::from(offset)).min(rtmax))
+    }
 }
 
 pub(crate) struct SignalInfo {
@@ -283,7 +304,11 @@ fn signal_enable(signal: SignalKind, handle: &Handle) -> io::Result<()> {
 ///   be yielded as an item.
 ///
 ///   Put another way, any element pulled off the returned stream corresponds to
-///   *at least one* signal, but possibly more.
+///   *at least one* signal, but possibly more. This coalescing isn't
+///   configurable: delivery is backed by a [`watch`](crate::sync::watch)
+///   channel carrying only "a signal of this kind arrived," not a count, so
+///   there's nowhere to plug in alternate semantics without a different
+///   delivery mechanism entirely.
 ///
 /// * Signal handling in general is relatively inefficient. Although some
 ///   improvements are possible in this crate, it's recommended to not plan on
@@ -375,6 +400,83 @@ pub(crate) fn signal_with_handle(
     Ok(globals().register_listener(kind.0 as EventId))
 }
 
+/// Creates a stream which receives a notification whenever any of the given
+/// signal kinds is delivered to this process, without having to `select!`
+/// over one individually-created [`Signal`] stream per kind.
+///
+/// Each item produced is the [`SignalKind`] that fired, not a count:
+/// coalescing works the same way it does for a single `Signal` (see its
+/// docs), independently per kind, so this only saves writing out the fan-in,
+/// not a way to count how many of each arrived.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`signal`], for whichever
+/// kind fails first.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tokio::signal::unix::{any_of, SignalKind};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut signals = any_of([
+///         SignalKind::interrupt(),
+///         SignalKind::terminate(),
+///         SignalKind::hangup(),
+///     ])?;
+///
+///     let kind = signals.recv().await;
+///     println!("got {:?}, shutting down", kind);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn any_of(kinds: impl IntoIterator<Item = SignalKind>) -> io::Result<SignalSet> {
+    let signals = kinds
+        .into_iter()
+        .map(|kind| signal(kind).map(|signal| (kind, signal)))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    Ok(SignalSet { signals })
+}
+
+/// A stream of signal notifications merged from several [`SignalKind`]s at
+/// once, created by [`any_of`].
+#[must_use = "streams do nothing unless polled"]
+#[derive(Debug)]
+pub struct SignalSet {
+    signals: Vec<(SignalKind, Signal)>,
+}
+
+impl SignalSet {
+    /// Receives the next signal notification event, returning which kind of
+    /// signal fired.
+    ///
+    /// `None` is returned if this set was constructed from an empty
+    /// iterator of kinds, and so can never receive anything.
+    pub async fn recv(&mut self) -> Option<SignalKind> {
+        crate::future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Polls to receive the next signal notification event, outside of an
+    /// `async` context.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<SignalKind>> {
+        if self.signals.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        for (kind, signal) in &mut self.signals {
+            if signal.poll_recv(cx).is_ready() {
+                return Poll::Ready(Some(*kind));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
 impl Signal {
     /// Receives the next signal notification event.
     ///
@@ -7,7 +7,9 @@ use crate::signal::RxFuture;
 
 use winapi::shared::minwindef::{BOOL, DWORD, FALSE, TRUE};
 use winapi::um::consoleapi::SetConsoleCtrlHandler;
-use winapi::um::wincon::{CTRL_BREAK_EVENT, CTRL_C_EVENT};
+use winapi::um::wincon::{
+    CTRL_BREAK_EVENT, CTRL_CLOSE_EVENT, CTRL_C_EVENT, CTRL_LOGOFF_EVENT, CTRL_SHUTDOWN_EVENT,
+};
 
 pub(super) fn ctrl_c() -> io::Result<RxFuture> {
     new(CTRL_C_EVENT)
@@ -17,6 +19,18 @@ pub(super) fn ctrl_break() -> io::Result<RxFuture> {
     new(CTRL_BREAK_EVENT)
 }
 
+pub(super) fn ctrl_close() -> io::Result<RxFuture> {
+    new(CTRL_CLOSE_EVENT)
+}
+
+pub(super) fn ctrl_logoff() -> io::Result<RxFuture> {
+    new(CTRL_LOGOFF_EVENT)
+}
+
+pub(super) fn ctrl_shutdown() -> io::Result<RxFuture> {
+    new(CTRL_SHUTDOWN_EVENT)
+}
+
 fn new(signum: DWORD) -> io::Result<RxFuture> {
     global_init()?;
     let rx = globals().register_listener(signum as EventId);
@@ -27,6 +41,9 @@ fn new(signum: DWORD) -> io::Result<RxFuture> {
 pub(crate) struct OsStorage {
     ctrl_c: EventInfo,
     ctrl_break: EventInfo,
+    ctrl_close: EventInfo,
+    ctrl_logoff: EventInfo,
+    ctrl_shutdown: EventInfo,
 }
 
 impl Init for OsStorage {
@@ -34,6 +51,9 @@ impl Init for OsStorage {
         Self {
             ctrl_c: EventInfo::default(),
             ctrl_break: EventInfo::default(),
+            ctrl_close: EventInfo::default(),
+            ctrl_logoff: EventInfo::default(),
+            ctrl_shutdown: EventInfo::default(),
         }
     }
 }
@@ -43,6 +63,9 @@ impl Storage for OsStorage {
         match DWORD::try_from(id) {
             Ok(CTRL_C_EVENT) => Some(&self.ctrl_c),
             Ok(CTRL_BREAK_EVENT) => Some(&self.ctrl_break),
+            Ok(CTRL_CLOSE_EVENT) => Some(&self.ctrl_close),
+            Ok(CTRL_LOGOFF_EVENT) => Some(&self.ctrl_logoff),
+            Ok(CTRL_SHUTDOWN_EVENT) => Some(&self.ctrl_shutdown),
             _ => None,
         }
     }
@@ -53,6 +76,9 @@ impl Storage for OsStorage {
     {
         f(&self.ctrl_c);
         f(&self.ctrl_break);
+        f(&self.ctrl_close);
+        f(&self.ctrl_logoff);
+        f(&self.ctrl_shutdown);
     }
 }
 
@@ -145,6 +171,51 @@ mod tests {
         });
     }
 
+    #[test]
+    fn ctrl_close() {
+        let rt = rt();
+
+        rt.block_on(async {
+            let mut ctrl_close = assert_ok!(crate::signal::windows::ctrl_close());
+
+            unsafe {
+                super::handler(CTRL_CLOSE_EVENT);
+            }
+
+            ctrl_close.recv().await.unwrap();
+        });
+    }
+
+    #[test]
+    fn ctrl_logoff() {
+        let rt = rt();
+
+        rt.block_on(async {
+            let mut ctrl_logoff = assert_ok!(crate::signal::windows::ctrl_logoff());
+
+            unsafe {
+                super::handler(CTRL_LOGOFF_EVENT);
+            }
+
+            ctrl_logoff.recv().await.unwrap();
+        });
+    }
+
+    #[test]
+    fn ctrl_shutdown() {
+        let rt = rt();
+
+        rt.block_on(async {
+            let mut ctrl_shutdown = assert_ok!(crate::signal::windows::ctrl_shutdown());
+
+            unsafe {
+                super::handler(CTRL_SHUTDOWN_EVENT);
+            }
+
+            ctrl_shutdown.recv().await.unwrap();
+        });
+    }
+
     fn rt() -> Runtime {
         crate::runtime::Builder::new_current_thread()
             .build()
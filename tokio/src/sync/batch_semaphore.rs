@@ -143,7 +143,7 @@ impl Semaphore {
     ///
     /// If the specified number of permits exceeds the maximum permit amount
     /// Then the value will get clamped to the maximum number of permits.
-    #[cfg(all(feature = "parking_lot", not(all(loom, test))))]
+    #[cfg(all(feature = "parking_lot", not(loom)))]
     pub(crate) const fn const_new(mut permits: usize) -> Self {
         // NOTE: assertions and by extension panics are still being worked on: https://github.com/rust-lang/rust/issues/74925
         // currently we just clamp the permit count when it exceeds the max
@@ -136,7 +136,7 @@ impl Semaphore {
     /// static SEM: Semaphore = Semaphore::const_new(10);
     /// ```
     ///
-    #[cfg(all(feature = "parking_lot", not(all(loom, test))))]
+    #[cfg(all(feature = "parking_lot", not(loom)))]
     #[cfg_attr(docsrs, doc(cfg(feature = "parking_lot")))]
     pub const fn const_new(permits: usize) -> Self {
         Self {
@@ -576,7 +576,7 @@ impl<T> ops::Deref for Ref<'_, T> {
     }
 }
 
-#[cfg(all(test, loom))]
+#[cfg(all(loom, test))]
 mod tests {
     use futures::future::FutureExt;
     use loom::thread;
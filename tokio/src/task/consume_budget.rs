@@ -0,0 +1,32 @@
+cfg_coop! {
+    use std::task::Poll;
+
+    /// Consumes a unit of budget from the task's cooperative scheduling
+    /// allowance and returns control back to the Tokio runtime if the task's
+    /// coop budget was exhausted.
+    ///
+    /// This is useful for situations where CPU-bound tasks are required to
+    /// compute for a long period of time without reaching an `.await` on a
+    /// Tokio resource, since such tasks will never yield back to the
+    /// executor on their own and can starve other tasks waiting on that
+    /// executor. `consume_budget` gives such a task a voluntary yield point
+    /// without requiring it to hit an actual Tokio resource.
+    ///
+    /// See also the usage example in the [task module](index.html#cooperative-scheduling).
+    pub async fn consume_budget() {
+        let mut status = Poll::Pending;
+
+        crate::future::poll_fn(|cx| {
+            if status.is_ready() {
+                return status;
+            }
+
+            status = crate::coop::poll_proceed(cx).map(|restore| {
+                restore.made_progress();
+            });
+
+            status
+        })
+        .await
+    }
+}
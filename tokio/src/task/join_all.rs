@@ -0,0 +1,151 @@
+use crate::task::JoinHandle;
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+/// Concurrently runs futures produced by `iter`, limiting the number that
+/// are in flight at any given time to `limit`, and returns their outputs in
+/// the order the futures were produced.
+///
+/// Each future is driven to completion on its own task (as if spawned with
+/// [`tokio::spawn`](crate::spawn)), so this must be called from within a
+/// Tokio runtime. Unlike driving an unbounded collection of futures
+/// concurrently, this caps the number of tasks that are runnable at once,
+/// which is useful when fanning out work (such as outbound connections)
+/// that would otherwise overwhelm a downstream limit.
+///
+/// # Panics
+///
+/// This function panics if `limit` is `0`, if called outside of a Tokio
+/// runtime, or if any of the provided futures panics (the panic is
+/// propagated to the caller).
+///
+/// # Examples
+///
+/// ```
+/// #[tokio::main]
+/// async fn main() {
+///     let futures = (0..10).map(|i| async move { i * 2 });
+///
+///     let results = tokio::task::join_all_limited(futures, 3).await;
+///
+///     assert_eq!(results, (0..10).map(|i| i * 2).collect::<Vec<_>>());
+/// }
+/// ```
+pub async fn join_all_limited<I>(iter: I, limit: usize) -> Vec<<I::Item as Future>::Output>
+where
+    I: IntoIterator,
+    I::Item: Future + Send + 'static,
+    <I::Item as Future>::Output: Send + 'static,
+{
+    assert!(limit > 0, "limit must be greater than zero");
+
+    let mut iter = iter.into_iter();
+    let mut handles: VecDeque<JoinHandle<<I::Item as Future>::Output>> =
+        VecDeque::with_capacity(limit);
+    let mut outputs = Vec::new();
+
+    for fut in iter.by_ref().take(limit) {
+        handles.push_back(crate::spawn(fut));
+    }
+
+    while let Some(handle) = handles.pop_front() {
+        match handle.await {
+            Ok(output) => outputs.push(output),
+            Err(join_err) => {
+                for handle in handles {
+                    handle.abort();
+                }
+                if join_err.is_panic() {
+                    std::panic::resume_unwind(join_err.into_panic());
+                } else {
+                    panic!("a task driven by join_all_limited was cancelled");
+                }
+            }
+        }
+
+        if let Some(fut) = iter.next() {
+            handles.push_back(crate::spawn(fut));
+        }
+    }
+
+    outputs
+}
+
+/// Like [`join_all_limited`], but for futures that resolve to a `Result`.
+///
+/// Outputs are awaited in the order the futures were produced. The first
+/// `Err` met in that order is returned and the remaining in-flight tasks are
+/// aborted; an `Err` from a later future is only seen once every earlier
+/// future has finished. Otherwise, the `Ok` values are returned in the order
+/// the futures were produced.
+///
+/// # Panics
+///
+/// This function panics if `limit` is `0`, if called outside of a Tokio
+/// runtime, or if any of the provided futures panics (the panic is
+/// propagated to the caller).
+///
+/// # Examples
+///
+/// ```
+/// #[tokio::main]
+/// async fn main() {
+///     let futures = (0..10).map(|i| async move {
+///         if i == 7 {
+///             Err("backend unreachable")
+///         } else {
+///             Ok(i)
+///         }
+///     });
+///
+///     let result = tokio::task::try_join_all_limited(futures, 3).await;
+///
+///     assert_eq!(result, Err("backend unreachable"));
+/// }
+/// ```
+pub async fn try_join_all_limited<I, T, E>(iter: I, limit: usize) -> Result<Vec<T>, E>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, E>> + Send + 'static,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    assert!(limit > 0, "limit must be greater than zero");
+
+    let mut iter = iter.into_iter();
+    let mut handles: VecDeque<JoinHandle<Result<T, E>>> = VecDeque::with_capacity(limit);
+    let mut outputs = Vec::new();
+
+    for fut in iter.by_ref().take(limit) {
+        handles.push_back(crate::spawn(fut));
+    }
+
+    while let Some(handle) = handles.pop_front() {
+        match handle.await {
+            Ok(Ok(value)) => outputs.push(value),
+            Ok(Err(err)) => {
+                for handle in handles {
+                    handle.abort();
+                }
+                return Err(err);
+            }
+            Err(join_err) => {
+                for handle in handles {
+                    handle.abort();
+                }
+                if join_err.is_panic() {
+                    std::panic::resume_unwind(join_err.into_panic());
+                } else {
+                    panic!("a task driven by try_join_all_limited was cancelled");
+                }
+            }
+        }
+
+        if let Some(fut) = iter.next() {
+            handles.push_back(crate::spawn(fut));
+        }
+    }
+
+    Ok(outputs)
+}
@@ -0,0 +1,194 @@
+use crate::runtime::Handle;
+use crate::task::JoinHandle;
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A collection of tasks spawned on a Tokio runtime, which can be awaited or
+/// aborted as a group.
+///
+/// A `JoinSet` can be used to await the completion of some or all of the
+/// tasks in the set. The tasks contained in a `JoinSet` are not ordered, and
+/// the first task to complete is the first one that will be returned from
+/// [`join_next`](Self::join_next).
+///
+/// Dropping a `JoinSet` aborts all of its tasks.
+///
+/// # Examples
+///
+/// Spawn several tasks and wait for them all to finish, handling both plain
+/// async tasks and CPU-bound blocking work with the same `JoinSet`:
+///
+/// ```
+/// use tokio::task::JoinSet;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut set = JoinSet::new();
+///
+///     for i in 0..5 {
+///         set.spawn(async move { i * 2 });
+///     }
+///
+///     set.spawn_blocking(|| {
+///         // some CPU-bound work
+///         5 * 5
+///     });
+///
+///     let mut seen = Vec::new();
+///     while let Some(res) = set.join_next().await {
+///         seen.push(res.unwrap());
+///     }
+///
+///     seen.sort_unstable();
+///     assert_eq!(seen, vec![0, 2, 4, 6, 8, 25]);
+/// }
+/// ```
+pub struct JoinSet<T> {
+    tasks: Vec<JoinHandle<T>>,
+}
+
+impl<T> JoinSet<T> {
+    /// Creates a new, empty `JoinSet`.
+    pub fn new() -> Self {
+        JoinSet { tasks: Vec::new() }
+    }
+
+    /// Returns the number of tasks currently in the `JoinSet`.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns whether the `JoinSet` is empty.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+impl<T: 'static> JoinSet<T> {
+    /// Spawns a task on the current runtime and stores its [`JoinHandle`]
+    /// in this `JoinSet`.
+    #[cfg_attr(tokio_track_caller, track_caller)]
+    pub fn spawn<F>(&mut self, task: F)
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send,
+    {
+        self.tasks.push(crate::spawn(task));
+    }
+
+    /// Spawns a task on the specified runtime [`Handle`] and stores its
+    /// [`JoinHandle`] in this `JoinSet`.
+    ///
+    /// This allows a single `JoinSet` to supervise tasks that run on a
+    /// different runtime than the one driving the `JoinSet` itself.
+    #[cfg_attr(tokio_track_caller, track_caller)]
+    pub fn spawn_on<F>(&mut self, task: F, handle: &Handle)
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send,
+    {
+        self.tasks.push(handle.spawn(task));
+    }
+
+    /// Spawns a blocking closure on the current runtime's blocking pool and
+    /// stores its [`JoinHandle`] in this `JoinSet`, so it is awaited and
+    /// aborted alongside the set's other tasks.
+    #[cfg_attr(tokio_track_caller, track_caller)]
+    pub fn spawn_blocking<F>(&mut self, f: F)
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send,
+    {
+        self.tasks.push(crate::task::spawn_blocking(f));
+    }
+
+    /// Spawns a blocking closure on the specified runtime [`Handle`]'s
+    /// blocking pool and stores its [`JoinHandle`] in this `JoinSet`.
+    #[cfg_attr(tokio_track_caller, track_caller)]
+    pub fn spawn_blocking_on<F>(&mut self, f: F, handle: &Handle)
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send,
+    {
+        self.tasks.push(handle.spawn_blocking(f));
+    }
+
+    /// Waits until one of the tasks in this `JoinSet` completes and returns
+    /// its output.
+    ///
+    /// Returns `None` if the `JoinSet` is empty.
+    pub async fn join_next(&mut self) -> Option<Result<T, super::JoinError>> {
+        JoinNext { set: self }.await
+    }
+
+    /// Aborts all tasks currently in this `JoinSet`.
+    ///
+    /// Aborted tasks remain in the `JoinSet` until [`join_next`](Self::join_next)
+    /// is called for them, at which point they will yield a cancelled
+    /// [`JoinError`](super::JoinError).
+    pub fn abort_all(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+
+    /// Aborts all tasks and waits for them to finish shutting down.
+    pub async fn shutdown(&mut self) {
+        self.abort_all();
+        while self.join_next().await.is_some() {}
+    }
+}
+
+impl<T> Default for JoinSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for JoinSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinSet")
+            .field("len", &self.tasks.len())
+            .finish()
+    }
+}
+
+impl<T> Drop for JoinSet<T> {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+struct JoinNext<'a, T> {
+    set: &'a mut JoinSet<T>,
+}
+
+impl<'a, T> Future for JoinNext<'a, T> {
+    type Output = Option<Result<T, super::JoinError>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let me = self.get_mut();
+
+        if me.set.tasks.is_empty() {
+            return Poll::Ready(None);
+        }
+
+        let mut i = 0;
+        while i < me.set.tasks.len() {
+            match Pin::new(&mut me.set.tasks[i]).poll(cx) {
+                Poll::Ready(res) => {
+                    me.set.tasks.swap_remove(i);
+                    return Poll::Ready(Some(res));
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+
+        Poll::Pending
+    }
+}
@@ -240,6 +240,24 @@
 //! To account for this, Tokio has explicit yield points in a number of library
 //! functions, which force tasks to return to the executor periodically.
 //!
+//! If a future does not internally call into any of those library functions,
+//! such as a CPU-bound loop that never touches a Tokio resource, it can use
+//! [`task::consume_budget`] to create its own yield point:
+//!
+//! ```
+//! async fn chunked_work(data: &[u8]) -> u64 {
+//!     let mut sum = 0;
+//!     for chunk in data.chunks(1024) {
+//!         sum += chunk.iter().map(|&b| b as u64).sum::<u64>();
+//!         // Insert a yield point every so often, so this task doesn't
+//!         // monopolize its worker if `data` is large.
+//!         tokio::task::consume_budget().await;
+//!     }
+//!     sum
+//! }
+//! ```
+//!
+//! [`task::consume_budget`]: crate::task::consume_budget()
 //!
 //! #### unconstrained
 //!
@@ -276,7 +294,7 @@
 //! [`poll`]: method@std::future::Future::poll
 
 cfg_rt! {
-    pub use crate::runtime::task::{JoinError, JoinHandle};
+    pub use crate::runtime::task::{AbortHandle, JoinError, JoinHandle};
 
     mod blocking;
     pub use blocking::spawn_blocking;
@@ -300,6 +318,15 @@ cfg_rt! {
     mod unconstrained;
     pub use unconstrained::{unconstrained, Unconstrained};
 
+    mod consume_budget;
+    pub use consume_budget::consume_budget;
+
+    mod join_all;
+    pub use join_all::{join_all_limited, try_join_all_limited};
+
+    mod join_set;
+    pub use join_set::JoinSet;
+
     cfg_trace! {
         mod builder;
         pub use builder::Builder;
@@ -121,6 +121,15 @@ cfg_rt! {
     /// ```text
     /// error[E0391]: cycle detected when processing `main`
     /// ```
+    ///
+    /// If the value must be held across an `.await` point — for example, an
+    /// `Rc` or `RefCell`-based state machine — the task cannot satisfy
+    /// `spawn`'s `Send` bound at all, regardless of scoping. Use
+    /// [`task::spawn_local`] on a [`LocalSet`] instead, which runs `!Send`
+    /// futures on the thread that drives the set.
+    ///
+    /// [`task::spawn_local`]: crate::task::spawn_local
+    /// [`LocalSet`]: crate::task::LocalSet
     #[cfg_attr(tokio_track_caller, track_caller)]
     pub fn spawn<T>(future: T) -> JoinHandle<T::Output>
     where
@@ -130,7 +130,7 @@ cfg_test_util! {
         let clock = clock().expect("time cannot be frozen from outside the Tokio runtime");
         clock.advance(duration);
 
-        crate::task::yield_now().await;
+        let _ = crate::task::yield_now().await;
     }
 
     /// Return the current instant, factoring in frozen time.
@@ -6,6 +6,7 @@ use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 use tokio_test::task;
 
 use std::io::prelude::*;
+use std::pin::Pin;
 use tempfile::NamedTempFile;
 
 const HELLO: &[u8] = b"hello world...";
@@ -37,6 +38,27 @@ async fn basic_write() {
     assert_eq!(file, HELLO);
 }
 
+#[tokio::test]
+async fn vectored_write() {
+    use std::io::IoSlice;
+    use tokio::io::AsyncWrite;
+
+    let tempfile = tempfile();
+
+    let mut file = File::create(tempfile.path()).await.unwrap();
+    assert!(file.is_write_vectored());
+
+    let bufs = [IoSlice::new(b"hello "), IoSlice::new(b"world...")];
+    let n = futures::future::poll_fn(|cx| Pin::new(&mut file).poll_write_vectored(cx, &bufs))
+        .await
+        .unwrap();
+    file.flush().await.unwrap();
+
+    assert_eq!(n, HELLO.len());
+    let file = std::fs::read(tempfile.path()).unwrap();
+    assert_eq!(file, HELLO);
+}
+
 #[tokio::test]
 async fn basic_write_and_shutdown() {
     let tempfile = tempfile();
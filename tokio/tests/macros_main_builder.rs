@@ -0,0 +1,25 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+fn shared_builder() -> tokio::runtime::Builder {
+    let mut builder = tokio::runtime::Builder::new_current_thread();
+    builder.thread_name("macros-main-builder-test").enable_all();
+    builder
+}
+
+#[tokio::main(builder = "shared_builder")]
+async fn custom_builder_main() -> i32 {
+    let _ = tokio::task::yield_now().await;
+    1 + 1
+}
+
+#[test]
+fn main_delegates_to_custom_builder() {
+    assert_eq!(custom_builder_main(), 2);
+}
+
+#[tokio::test(builder = "shared_builder")]
+async fn test_delegates_to_custom_builder() {
+    let _ = tokio::task::yield_now().await;
+    assert_eq!(1 + 1, 2);
+}
@@ -11,3 +11,13 @@ async fn multi_pin() {
     (&mut f1).await;
     (&mut f2).await;
 }
+
+#[tokio::test]
+async fn multi_pin_idents() {
+    let f1 = one();
+    let f2 = two();
+    tokio::pin!(f1, f2);
+
+    (&mut f1).await;
+    (&mut f2).await;
+}
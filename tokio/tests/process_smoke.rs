@@ -32,3 +32,53 @@ async fn simple() {
     assert_eq!(child.id(), None);
     drop(child.kill());
 }
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[tokio::test]
+async fn wait_for_exit_non_child() {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg("sleep 0.1")
+        .spawn()
+        .unwrap();
+
+    let pid = child.id().expect("missing id");
+
+    // `wait_for_exit` doesn't require `child` to be our own child, but
+    // using a real one keeps this test from depending on some unrelated
+    // PID in the system happening to still be running.
+    match tokio::process::wait_for_exit(pid).await {
+        Ok(()) => {}
+        // `pidfd_open(2)` needs Linux 5.3+; skip on older kernels.
+        Err(e) if e.kind() == std::io::ErrorKind::Unsupported => return,
+        Err(e) => panic!("wait_for_exit failed: {}", e),
+    }
+    assert_ok!(child.wait().await);
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn pidfd_refers_to_the_running_child_and_none_after_exit() {
+    use std::os::unix::io::AsRawFd;
+
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg("sleep 0.1")
+        .spawn()
+        .unwrap();
+
+    let pidfd = match child.pidfd() {
+        Some(Ok(pidfd)) => pidfd,
+        // `pidfd_open(2)` needs Linux 5.3+; skip on older kernels.
+        Some(Err(e)) if e.kind() == std::io::ErrorKind::Unsupported => return,
+        Some(Err(e)) => panic!("pidfd failed: {}", e),
+        None => panic!("child should still be running"),
+    };
+    assert!(pidfd.as_raw_fd() >= 0);
+
+    assert_ok!(child.wait().await);
+
+    // Once the child has been reaped, there's no PID left to open a pidfd
+    // for.
+    assert!(child.pidfd().is_none());
+}
@@ -0,0 +1,102 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::runtime::Builder;
+use tokio::sync::Notify;
+
+#[test]
+fn blocking_tasks_over_the_queue_depth_are_rejected() {
+    let rt = Builder::new_current_thread()
+        .max_blocking_threads(1)
+        .max_blocking_queue_depth(1)
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let release = Arc::new(Notify::new());
+
+        // Occupies the only blocking thread until we tell it to stop.
+        let release_clone = release.clone();
+        let _busy = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(release_clone.notified());
+        });
+
+        // Fills the one queue slot the pool allows.
+        let _queued = tokio::task::spawn_blocking(|| {});
+
+        // The queue is now at capacity, so this one is rejected outright.
+        let rejected = tokio::task::spawn_blocking(|| 1);
+        release.notify_one();
+        assert!(rejected.await.is_err());
+    });
+}
+
+#[test]
+#[cfg(tokio_unstable)]
+fn idle_blocking_threads_exit_after_keep_alive_timeout() {
+    use tokio::time::sleep;
+
+    let rt = Builder::new_current_thread()
+        .enable_time()
+        .max_blocking_threads(1)
+        .thread_keep_alive(Duration::from_millis(50))
+        .build()
+        .unwrap();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async {
+        tokio::task::spawn_blocking(|| {}).await.unwrap();
+
+        // The thread lingers, idle, until the keep-alive timeout elapses.
+        assert_eq!(handle.blocking_pool_metrics().num_idle_threads(), 1);
+
+        // Give the idle thread a generous margin past the configured
+        // keep-alive timeout to notice and exit.
+        sleep(Duration::from_millis(500)).await;
+
+        assert_eq!(handle.blocking_pool_metrics().num_idle_threads(), 0);
+    });
+}
+
+#[test]
+#[cfg(tokio_unstable)]
+fn metrics_reflect_queue_depth_and_executed_count() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::sync::Barrier;
+
+    let rt = Builder::new_current_thread()
+        .max_blocking_threads(1)
+        .build()
+        .unwrap();
+    let handle = rt.handle().clone();
+
+    rt.block_on(async {
+        let barrier = Arc::new(Barrier::new(2));
+        let barrier_clone = barrier.clone();
+        let started = Arc::new(AtomicUsize::new(0));
+        let started_clone = started.clone();
+
+        let task = tokio::task::spawn_blocking(move || {
+            started_clone.fetch_add(1, Ordering::SeqCst);
+            futures::executor::block_on(barrier_clone.wait());
+        });
+
+        // Wait until the blocking thread has actually started the task.
+        while started.load(Ordering::SeqCst) == 0 {
+            let _ = tokio::task::yield_now().await;
+        }
+
+        let metrics = handle.blocking_pool_metrics();
+        assert_eq!(metrics.num_threads(), 1);
+        assert_eq!(metrics.num_idle_threads(), 0);
+
+        barrier.wait().await;
+        task.await.unwrap();
+
+        let metrics = handle.blocking_pool_metrics();
+        assert_eq!(metrics.num_tasks_executed(), 1);
+    });
+}
@@ -854,7 +854,10 @@ rt_test! {
             rx.await.unwrap();
         });
 
-        Arc::try_unwrap(runtime).unwrap().shutdown_timeout(Duration::from_millis(100));
+        let completed = Arc::try_unwrap(runtime)
+            .unwrap()
+            .shutdown_timeout_status(Duration::from_millis(100));
+        assert!(!completed);
     }
 
     #[test]
@@ -868,7 +871,10 @@ rt_test! {
         });
 
         let now = Instant::now();
-        Arc::try_unwrap(runtime).unwrap().shutdown_timeout(Duration::from_nanos(0));
+        let completed = Arc::try_unwrap(runtime)
+            .unwrap()
+            .shutdown_timeout_status(Duration::from_nanos(0));
+        assert!(!completed);
         assert!(now.elapsed().as_secs() < 1);
     }
 
@@ -880,7 +886,10 @@ rt_test! {
             tokio::time::sleep(std::time::Duration::from_millis(100)).await;
         });
 
-        Arc::try_unwrap(runtime).unwrap().shutdown_timeout(Duration::from_secs(10_000));
+        let completed = Arc::try_unwrap(runtime)
+            .unwrap()
+            .shutdown_timeout_status(Duration::from_secs(10_000));
+        assert!(completed);
     }
 
     // This test is currently ignored on Windows because of a
@@ -1043,6 +1052,36 @@ rt_test! {
         });
     }
 
+    #[test]
+    fn coop_consume_budget() {
+        use std::task::Poll;
+
+        let rt = rt();
+
+        rt.block_on(async {
+            // Repeatedly polling `consume_budget`'s future should eventually
+            // run out of the task's coop budget and return `Poll::Pending`,
+            // proving that a hot loop built purely out of `consume_budget`
+            // calls (and nothing else that touches a Tokio resource) still
+            // yields back to the executor periodically.
+            let mut successful_polls = 0;
+
+            poll_fn(|cx| {
+                loop {
+                    let mut fut = Box::pin(tokio::task::consume_budget());
+                    match fut.as_mut().poll(cx) {
+                        Poll::Ready(()) => successful_polls += 1,
+                        Poll::Pending => return Poll::Ready(()),
+                    }
+                }
+            })
+            .await;
+
+            assert!(successful_polls > 0);
+            assert!(successful_polls < 1_000);
+        });
+    }
+
     // Tests that the "next task" scheduler optimization is not able to starve
     // other tasks.
     #[test]
@@ -1106,4 +1145,78 @@ rt_test! {
             }
         });
     }
+
+    // Tests that a task woken up by another task gets to run promptly via the
+    // per-worker LIFO slot, rather than waiting behind a queue of always-ready
+    // background tasks. The current-thread scheduler has no LIFO slot; there
+    // this only checks the time bound.
+    #[test]
+    fn lifo_slot_keeps_ping_pong_latency_low() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::time::{Duration, Instant};
+        use tokio::sync::mpsc;
+
+        const BACKGROUND_TASKS: usize = 100;
+        const ROUND_TRIPS: usize = 50;
+
+        let rt = rt();
+
+        let running = Arc::new(AtomicBool::new(true));
+
+        rt.block_on(async {
+            // Keep every worker's local run queue perpetually non-empty, so
+            // that a task relying on FIFO-only scheduling would be stuck
+            // behind a long line of always-ready work.
+            let mut background = vec![];
+            for _ in 0..BACKGROUND_TASKS {
+                let running = running.clone();
+                background.push(task::spawn(async move {
+                    while running.load(Ordering::Relaxed) {
+                        task::yield_now().await;
+                    }
+                }));
+            }
+
+            let (tx1, mut rx1) = mpsc::unbounded_channel();
+            let (tx2, mut rx2) = mpsc::unbounded_channel();
+
+            let responder = task::spawn(async move {
+                while let Some(()) = rx1.recv().await {
+                    tx2.send(()).unwrap();
+                }
+            });
+
+            // The pinger is a spawned task too, so that both wakeups of a
+            // round trip come from a task running on a worker.
+            let pinger = task::spawn(async move {
+                let start = Instant::now();
+                for _ in 0..ROUND_TRIPS {
+                    tx1.send(()).unwrap();
+                    rx2.recv().await.unwrap();
+                }
+                start.elapsed()
+            });
+            let elapsed = pinger.await.unwrap();
+
+            responder.await.unwrap();
+
+            running.store(false, Ordering::Relaxed);
+            for t in background {
+                t.await.unwrap();
+            }
+
+            // Each round trip wakes the responder and then the pinger, both
+            // of which land in the LIFO slot of the worker that woke them on
+            // the multi-thread scheduler.
+            // If that weren't the case, each wakeup would instead join the
+            // back of a queue behind 100 always-ready background tasks,
+            // which would make this take far longer than this generous
+            // bound allows.
+            assert!(
+                elapsed < Duration::from_secs(5),
+                "ping-pong round trips took too long: {:?}",
+                elapsed
+            );
+        });
+    }
 }
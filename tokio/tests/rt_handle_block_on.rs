@@ -202,6 +202,26 @@ rt_test! {
         assert_eq!(answer, 42);
     }
 
+    // A library holding only a `Handle` (not the `Runtime` itself) should
+    // still be able to offload blocking work, without going through the
+    // free-standing `task::spawn_blocking` function.
+    #[test]
+    fn handle_spawn_blocking_without_runtime_in_scope() {
+        let rt = rt();
+        // Only the `Handle` is passed around from here on; `rt` is kept
+        // alive in the background purely to keep the runtime running.
+        let handle = rt.handle().clone();
+
+        let answer = handle
+            .block_on(handle.spawn_blocking(|| {
+                std::thread::sleep(Duration::from_millis(100));
+                42
+            }))
+            .unwrap();
+
+        assert_eq!(answer, 42);
+    }
+
     // ==== net ======
 
     #[test]
@@ -0,0 +1,65 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Handle};
+
+#[test]
+fn spawns_when_a_runtime_is_active() {
+    let rt = Builder::new_current_thread().build().unwrap();
+    let ran = Arc::new(AtomicBool::new(false));
+
+    rt.block_on(async {
+        let ran = ran.clone();
+        Handle::defer_spawn(async move {
+            ran.store(true, Ordering::SeqCst);
+        });
+
+        // Give the spawned task a chance to run before the runtime shuts down.
+        let _ = tokio::task::yield_now().await;
+    });
+
+    assert!(ran.load(Ordering::SeqCst));
+}
+
+#[test]
+fn does_nothing_without_an_active_runtime() {
+    // No runtime has been entered on this thread, so this must not panic,
+    // and the future must simply be dropped.
+    let dropped = Arc::new(AtomicBool::new(false));
+
+    struct SetOnDrop(Arc<AtomicBool>);
+    impl Drop for SetOnDrop {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let guard = SetOnDrop(dropped.clone());
+    Handle::defer_spawn(async move {
+        let _guard = guard;
+    });
+
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[test]
+fn safe_to_call_from_drop_after_runtime_is_dropped() {
+    struct DefersOnDrop;
+
+    impl Drop for DefersOnDrop {
+        fn drop(&mut self) {
+            Handle::defer_spawn(async {});
+        }
+    }
+
+    let rt = Builder::new_current_thread().build().unwrap();
+    let guard = rt.block_on(async { DefersOnDrop });
+
+    // The runtime is gone by the time `guard` is dropped here, so
+    // `defer_spawn` must find no active runtime and simply drop the future.
+    drop(rt);
+    drop(guard);
+}
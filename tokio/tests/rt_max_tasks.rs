@@ -0,0 +1,53 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use std::future::pending;
+
+use tokio::runtime::Builder;
+
+#[test]
+fn tasks_over_the_limit_are_cancelled_current_thread() {
+    let rt = Builder::new_current_thread()
+        .max_tasks(1)
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let _first = tokio::spawn(pending::<()>());
+
+        // The limit is already reached, so this task is never actually
+        // scheduled to run, and resolves to a `JoinError` instead of hanging.
+        let second = tokio::spawn(async { 1 });
+        assert!(second.await.is_err());
+    });
+}
+
+#[test]
+fn tasks_over_the_limit_are_cancelled_multi_thread() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .max_tasks(1)
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let _first = tokio::spawn(pending::<()>());
+
+        let second = tokio::spawn(async { 1 });
+        assert!(second.await.is_err());
+    });
+}
+
+#[test]
+fn completed_tasks_free_up_capacity() {
+    let rt = Builder::new_current_thread()
+        .max_tasks(1)
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        assert_eq!(tokio::spawn(async { 1 }).await.unwrap(), 1);
+        // The first task has already completed, freeing its slot.
+        assert_eq!(tokio::spawn(async { 2 }).await.unwrap(), 2);
+    });
+}
@@ -0,0 +1,96 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::runtime::Runtime;
+use tokio::sync::Semaphore;
+use tokio::time::sleep;
+
+fn rt() -> Runtime {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(4)
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn never_exceeds_the_permit_count() {
+    let rt = rt();
+    let handle = rt.handle();
+
+    let semaphore = Arc::new(Semaphore::new(2));
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+    let handles: Vec<_> = (0..10)
+        .map(|_| {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            handle.spawn_with_permit(semaphore.clone(), async move {
+                let cur = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(cur, Ordering::SeqCst);
+                sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+            })
+        })
+        .collect();
+
+    rt.block_on(async move {
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+    });
+
+    assert!(max_in_flight.load(Ordering::SeqCst) <= 2);
+}
+
+#[test]
+fn releases_the_permit_on_abort() {
+    let rt = rt();
+    let handle = rt.handle();
+
+    let semaphore = Arc::new(Semaphore::new(1));
+
+    let blocked = handle.spawn_with_permit(semaphore.clone(), async {
+        std::future::pending::<()>().await;
+    });
+
+    rt.block_on(async {
+        // Give the task a chance to acquire the permit before aborting it.
+        let _ = tokio::task::yield_now().await;
+        blocked.abort();
+        let _ = blocked.await;
+
+        // The permit must have been released, so a new task can acquire it.
+        let released = handle.spawn_with_permit(semaphore.clone(), async { 42 });
+        assert_eq!(released.await.unwrap().unwrap(), 42);
+    });
+}
+
+#[test]
+fn closing_the_semaphore_fails_queued_tasks_instead_of_panicking() {
+    let rt = rt();
+    let handle = rt.handle();
+
+    let semaphore = Arc::new(Semaphore::new(1));
+
+    rt.block_on(async {
+        // Take the only permit so the next spawn has to queue for it.
+        let permit = semaphore.clone().acquire_owned().await.unwrap();
+
+        let queued = handle.spawn_with_permit(semaphore.clone(), async {
+            unreachable!("future must not run once its semaphore is closed");
+        });
+
+        // Give the queued task a chance to start waiting on the semaphore.
+        let _ = tokio::task::yield_now().await;
+        semaphore.close();
+        drop(permit);
+
+        assert!(queued.await.unwrap().is_err());
+    });
+}
@@ -0,0 +1,177 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[test]
+fn fires_for_a_worker_stuck_in_a_single_poll() {
+    let hits: Arc<Mutex<Vec<(usize, Duration)>>> = Arc::new(Mutex::new(Vec::new()));
+    let hits2 = hits.clone();
+
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .on_thread_unresponsive(Duration::from_millis(50), move |worker, blocked_for| {
+            hits2.lock().unwrap().push((worker, blocked_for));
+        })
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        tokio::task::spawn_blocking(|| {}).await.unwrap();
+
+        // Blocks a worker thread inside a single task poll for well beyond
+        // the configured threshold.
+        tokio::spawn(async {
+            std::thread::sleep(Duration::from_millis(300));
+        })
+        .await
+        .unwrap();
+
+        // Give the watchdog a few scan intervals to notice and report.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    });
+
+    let hits = hits.lock().unwrap();
+    assert!(!hits.is_empty(), "watchdog never reported the stuck worker");
+    assert!(hits.iter().all(|(_, blocked_for)| *blocked_for >= Duration::from_millis(50)));
+}
+
+#[test]
+fn does_not_fire_for_well_behaved_tasks() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls2 = calls.clone();
+
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .on_thread_unresponsive(Duration::from_secs(5), move |_, _| {
+            calls2.fetch_add(1, Ordering::SeqCst);
+        })
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        for _ in 0..100 {
+            tokio::spawn(async { 1 + 1 }).await.unwrap();
+        }
+    });
+
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+// The watchdog itself only ever learns *which worker* and *for how long*;
+// it deliberately does not duplicate the task-name bookkeeping that the
+// `tracing` unstable feature already attaches to every task (see the
+// module doc comment on `runtime::thread_pool::watchdog` and
+// `Builder::on_thread_unresponsive`). This test demonstrates the intended
+// pairing: a `task::Builder`-named task whose span is still active while
+// the watchdog fires, so a subscriber can correlate the stalled worker
+// with the task name by reading the current span instead of the watchdog
+// callback's arguments. Tokio has no backtrace-capturing dependency, so
+// that part of the ask is out of scope here; a panic hook or an external
+// crate such as `backtrace` would need to be layered on top by the caller.
+#[cfg(all(tokio_unstable, feature = "tracing"))]
+#[test]
+fn stuck_task_name_is_available_via_tracing_span() {
+    use std::sync::Mutex as StdMutex;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    struct NameVisitor<'a>(&'a mut Option<String>);
+
+    impl<'a> Visit for NameVisitor<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "task.name" {
+                *self.0 = Some(format!("{:?}", value));
+            }
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct RecordingSubscriber {
+        names: Arc<StdMutex<Vec<String>>>,
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut name = None;
+            attrs.record(&mut NameVisitor(&mut name));
+            if let Some(name) = name {
+                self.names.lock().unwrap().push(name);
+            }
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, _event: &Event<'_>) {}
+        fn enter(&self, _span: &Id) {}
+        fn exit(&self, _span: &Id) {}
+    }
+
+    let hits: Arc<Mutex<Vec<(usize, Duration)>>> = Arc::new(Mutex::new(Vec::new()));
+    let hits2 = hits.clone();
+
+    let subscriber = RecordingSubscriber::default();
+    let names = subscriber.names.clone();
+
+    tracing::subscriber::with_default(subscriber, || {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .on_thread_unresponsive(Duration::from_millis(50), move |worker, blocked_for| {
+                hits2.lock().unwrap().push((worker, blocked_for));
+            })
+            .enable_all()
+            .build()
+            .unwrap();
+
+        rt.block_on(async {
+            tokio::task::Builder::new()
+                .name("the-stuck-task")
+                .spawn(async {
+                    std::thread::sleep(Duration::from_millis(300));
+                })
+                .await
+                .unwrap();
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        });
+    });
+
+    assert!(
+        !hits.lock().unwrap().is_empty(),
+        "watchdog never reported the stuck worker"
+    );
+    assert!(
+        names
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|name| name.contains("the-stuck-task")),
+        "the stuck task's tracing span never carried its name"
+    );
+}
+
+#[test]
+fn background_thread_does_not_block_runtime_shutdown() {
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .on_thread_unresponsive(Duration::from_secs(60), |_, _| {})
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async { tokio::task::yield_now().await });
+
+    let start = Instant::now();
+    drop(rt);
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
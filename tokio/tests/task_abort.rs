@@ -202,6 +202,51 @@ fn test_abort_task_that_panics_on_drop_contained() {
     });
 }
 
+/// Checks that an `AbortHandle` can still abort a task after its `JoinHandle`
+/// has been dropped.
+#[test]
+fn test_abort_handle_outlives_join_handle() {
+    let rt = Builder::new_current_thread().enable_time().build().unwrap();
+
+    rt.block_on(async move {
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(Duration::new(100, 0)).await;
+        });
+
+        let abort_handle = handle.abort_handle();
+        drop(handle);
+
+        abort_handle.abort();
+
+        // Give the task a chance to notice the abort and finish shutting
+        // down. There's nothing left to join on since the `JoinHandle` was
+        // dropped, so there's no result to assert on here beyond not
+        // panicking or hanging.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    });
+}
+
+/// Checks that cloned `AbortHandle`s all abort the same underlying task.
+#[test]
+fn test_abort_handle_clone() {
+    let rt = Builder::new_current_thread().enable_time().build().unwrap();
+
+    rt.block_on(async move {
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(Duration::new(100, 0)).await;
+        });
+
+        let abort_handle = handle.abort_handle();
+        let abort_handle2 = abort_handle.clone();
+
+        // wait for task to sleep.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        abort_handle2.abort();
+        assert!(handle.await.unwrap_err().is_cancelled());
+    });
+}
+
 /// Checks that aborting a task whose destructor panics has the expected result.
 #[test]
 fn test_abort_task_that_panics_on_drop_returned() {
@@ -98,6 +98,27 @@ fn no_block_in_basic_block_on() {
     });
 }
 
+// Unlike `spawn_blocking`, `block_in_place` does not require its closure to
+// be `Send` or `'static`, since it runs inline on the current task's stack
+// rather than being moved to another thread. This allows it to borrow local
+// data, such as when calling into a synchronous library that takes borrowed
+// arguments.
+#[test]
+fn block_in_place_can_borrow_from_the_stack() {
+    let rt = runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let data = String::from("hello");
+
+        let len = task::block_in_place(|| {
+            thread::sleep(Duration::from_millis(5));
+            data.len()
+        });
+
+        assert_eq!(len, data.len());
+    });
+}
+
 #[test]
 fn can_enter_basic_rt_from_within_block_in_place() {
     let outer = tokio::runtime::Runtime::new().unwrap();
@@ -1,10 +1,72 @@
 #[cfg(all(tokio_unstable, feature = "tracing"))]
 mod tests {
     use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
     use tokio::{
         task::{Builder, LocalSet},
         test,
     };
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    // Records the `task.name` field of every span it sees, so tests can
+    // assert that `Builder::name` actually reaches the tracing span that
+    // `tokio::task` emits for the spawned task, rather than just checking
+    // that the task still runs.
+    #[derive(Clone, Default)]
+    struct RecordingSubscriber {
+        names: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct NameVisitor<'a>(&'a mut Option<String>);
+
+    impl<'a> Visit for NameVisitor<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "task.name" {
+                *self.0 = Some(format!("{:?}", value));
+            }
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut name = None;
+            attrs.record(&mut NameVisitor(&mut name));
+            if let Some(name) = name {
+                self.names.lock().unwrap().push(name);
+            }
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, _event: &Event<'_>) {}
+        fn enter(&self, _span: &Id) {}
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    async fn spawn_with_name_emits_tracing_span() {
+        let subscriber = RecordingSubscriber::default();
+        let names = subscriber.names.clone();
+
+        let handle = tracing::subscriber::with_default(subscriber, || {
+            Builder::new()
+                .name("conn-1234")
+                .spawn(async { "task executed" })
+        });
+
+        let result = handle.await;
+        assert_eq!(result.unwrap(), "task executed");
+
+        let captured = names.lock().unwrap();
+        assert!(captured.iter().any(|name| name.contains("conn-1234")));
+    }
 
     #[test]
     async fn spawn_with_name() {
@@ -0,0 +1,70 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task;
+use tokio::time::sleep;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn preserves_order_with_out_of_order_completion() {
+    let delays = vec![30, 10, 20, 0, 15];
+    let futures = delays
+        .clone()
+        .into_iter()
+        .map(|ms| async move {
+            sleep(Duration::from_millis(ms)).await;
+            ms
+        });
+
+    let results = task::join_all_limited(futures, 2).await;
+
+    assert_eq!(results, delays);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn never_exceeds_the_concurrency_limit() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+    let futures = (0..20).map(|_| {
+        let in_flight = in_flight.clone();
+        let max_in_flight = max_in_flight.clone();
+        async move {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(current, Ordering::SeqCst);
+            sleep(Duration::from_millis(5)).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        }
+    });
+
+    task::join_all_limited(futures, 3).await;
+
+    assert!(max_in_flight.load(Ordering::SeqCst) <= 3);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn try_join_all_limited_returns_first_error() {
+    let futures = (0..10).map(|i| async move {
+        if i == 4 {
+            Err::<u32, _>("boom")
+        } else {
+            Ok(i)
+        }
+    });
+
+    let result = task::try_join_all_limited(futures, 2).await;
+
+    assert_eq!(result, Err("boom"));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn try_join_all_limited_collects_all_ok_values() {
+    let futures = (0..10).map(|i| async move { Ok::<_, &'static str>(i * 2) });
+
+    let result = task::try_join_all_limited(futures, 3).await;
+
+    assert_eq!(result, Ok((0..10).map(|i| i * 2).collect::<Vec<_>>()));
+}
@@ -0,0 +1,103 @@
+#![warn(rust_2018_idioms)]
+#![cfg(feature = "full")]
+
+use tokio::runtime::Runtime;
+use tokio::task::JoinSet;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn spawn_and_join_all() {
+    let mut set = JoinSet::new();
+
+    for i in 0..10 {
+        set.spawn(async move { i });
+    }
+
+    let mut seen = Vec::new();
+    while let Some(res) = set.join_next().await {
+        seen.push(res.unwrap());
+    }
+
+    seen.sort_unstable();
+    assert_eq!(seen, (0..10).collect::<Vec<_>>());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn spawn_blocking_joins_the_same_set() {
+    let mut set = JoinSet::new();
+
+    set.spawn(async { 1 });
+    set.spawn_blocking(|| 2);
+
+    let mut seen = Vec::new();
+    while let Some(res) = set.join_next().await {
+        seen.push(res.unwrap());
+    }
+
+    seen.sort_unstable();
+    assert_eq!(seen, vec![1, 2]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn spawn_on_a_different_handle() {
+    let other_rt = Box::leak(Box::new(Runtime::new().unwrap()));
+    let other_handle = other_rt.handle().clone();
+
+    let mut set = JoinSet::new();
+    set.spawn_on(async { 42 }, &other_handle);
+    set.spawn_blocking_on(|| 43, &other_handle);
+
+    let mut seen = Vec::new();
+    while let Some(res) = set.join_next().await {
+        seen.push(res.unwrap());
+    }
+
+    seen.sort_unstable();
+    assert_eq!(seen, vec![42, 43]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn join_next_returns_none_when_empty() {
+    let mut set: JoinSet<()> = JoinSet::new();
+    assert!(set.join_next().await.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn dropping_the_set_aborts_outstanding_tasks() {
+    use std::sync::Arc;
+    use tokio::sync::Notify;
+
+    let started = Arc::new(Notify::new());
+    let started2 = started.clone();
+
+    let mut set = JoinSet::new();
+    set.spawn(async move {
+        started2.notify_one();
+        futures_block_forever().await;
+    });
+
+    started.notified().await;
+    drop(set);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn abort_all_cancels_outstanding_tasks() {
+    let mut set = JoinSet::new();
+
+    for _ in 0..10 {
+        set.spawn(futures_block_forever());
+    }
+
+    set.abort_all();
+
+    let mut cancelled = 0;
+    while let Some(res) = set.join_next().await {
+        assert!(res.unwrap_err().is_cancelled());
+        cancelled += 1;
+    }
+
+    assert_eq!(cancelled, 10);
+}
+
+async fn futures_block_forever() {
+    std::future::pending::<()>().await;
+}